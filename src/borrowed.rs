@@ -0,0 +1,577 @@
+use std::io::{self, BufWriter, Write};
+
+enum Piece<'a> {
+    Glue,
+    Elem(&'a str),
+}
+
+/// A `Welder` that only borrows the elements and the glue it accumulates.
+///
+/// Nothing is allocated for the output until `weld` is called,
+/// the resulting `String` is then allocated once with the exact capacity needed.
+pub struct BorrowedWelder<'a> {
+    glue: &'a str,
+    pieces: Vec<Piece<'a>>,
+}
+
+impl<'a> BorrowedWelder<'a> {
+    /// Create an empty `BorrowedWelder` just by defining the glue used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// assert_eq!("", &welder.weld());
+    /// ```
+    pub fn new(glue: &'a str) -> Self {
+        BorrowedWelder {
+            glue,
+            pieces: Vec::new(),
+        }
+    }
+
+    /// Create a `BorrowedWelder` with a first value and the glue it will use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(" ", "foo");
+    ///
+    /// assert_eq!("foo", &welder.weld());
+    /// ```
+    pub fn with_start(glue: &'a str, start: &'a str) -> Self {
+        BorrowedWelder::new(glue).elem_no_glue(start)
+    }
+
+    /// Allocate the output with the exact capacity and copy every piece into it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(", ", "foo");
+    ///
+    /// let string = welder.elem("bar").elem("baz").weld();
+    ///
+    /// assert_eq!("foo, bar, baz", &string);
+    /// assert_eq!(string.len(), string.capacity());
+    /// ```
+    pub fn weld(self) -> String {
+        let len = self.pieces.iter().map(|p| self.piece_str(p).len()).sum();
+        let mut string = String::with_capacity(len);
+        for piece in &self.pieces {
+            string.push_str(self.piece_str(piece));
+        }
+        string
+    }
+
+    /// Write every piece, in order, to the writer without allocating the output.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(", ", "foo").elem("bar");
+    ///
+    /// let mut bytes = Vec::new();
+    /// welder.weld_to_writer(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(b"foo, bar", bytes.as_slice());
+    /// ```
+    pub fn weld_to_writer<W: Write>(self, mut writer: W) -> io::Result<()> {
+        for piece in &self.pieces {
+            writer.write_all(self.piece_str(piece).as_bytes())?;
+        }
+        writer.flush()
+    }
+
+    /// Write every piece, in order, to the standard output,
+    /// the standard output is locked once for the whole output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new("\n").elems_glue_right(vec!["foo", "bar"]);
+    ///
+    /// welder.weld_to_stdout().unwrap();
+    /// ```
+    pub fn weld_to_stdout(self) -> io::Result<()> {
+        let stdout = io::stdout();
+        let lock = stdout.lock();
+        self.weld_to_writer(BufWriter::new(lock))
+    }
+
+    /// Write every piece, in order, to the standard error,
+    /// the standard error is locked once for the whole output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(": ", "error").elem("file not found\n");
+    ///
+    /// welder.weld_to_stderr().unwrap();
+    /// ```
+    pub fn weld_to_stderr(self) -> io::Result<()> {
+        let stderr = io::stderr();
+        let lock = stderr.lock();
+        self.weld_to_writer(BufWriter::new(lock))
+    }
+
+    fn piece_str(&self, piece: &Piece<'a>) -> &'a str {
+        match *piece {
+            Piece::Glue => self.glue,
+            Piece::Elem(elem) => elem,
+        }
+    }
+
+    /// Weld the elements into several strings of at most `n` elements each,
+    /// the elements of a shard are glued together, no glue is kept between shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(", ", "a").elems(vec!["b", "c", "d", "e"]);
+    ///
+    /// assert_eq!(vec!["a, b", "c, d", "e"], welder.weld_shards(2));
+    /// ```
+    pub fn weld_shards(self, n: usize) -> Vec<String> {
+        assert!(n != 0, "shards must contain at least one element");
+
+        let glue = self.glue;
+        let elems: Vec<_> = self.pieces.into_iter().filter_map(elem_only).collect();
+        elems.chunks(n).map(|chunk| {
+            let (first, others) = chunk.split_first().expect("chunks are never empty");
+            BorrowedWelder::with_start(glue, first).elems(others.iter().cloned()).weld()
+        })
+        .collect()
+    }
+
+    /// Weld the elements into lines that are at most `width` characters wide,
+    /// a line only breaks where there is a glue and never inside of an element,
+    /// the elements added without glue between them stay on the same line.
+    ///
+    /// The glues are not kept at line breaks, only their trailing whitespaces are removed
+    /// so that a line ends with the visible part of the glues, if it fits in the width.
+    /// The glues in front of the first element are also dropped if they do not fit.
+    /// An element wider than `width` is put alone on its own line.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(", ", "foo").elems(vec!["bar", "baz", "boat"]);
+    ///
+    /// assert_eq!(vec!["foo, bar,", "baz, boat"], welder.weld_lines(10));
+    /// ```
+    pub fn weld_lines(self, width: usize) -> Vec<String> {
+        let glue = self.glue;
+
+        // the runs of elements that are not separated by any glue,
+        // along with the number of glues in front of each of them
+        let mut runs: Vec<(usize, Vec<&str>)> = Vec::new();
+        let mut glues = 0;
+        for piece in self.pieces {
+            match piece {
+                Piece::Glue => glues += 1,
+                Piece::Elem(elem) => {
+                    match runs.last_mut() {
+                        Some(&mut (_, ref mut run)) if glues == 0 => run.push(elem),
+                        _ => runs.push((glues, vec![elem])),
+                    }
+                    glues = 0;
+                },
+            }
+        }
+        let trailing = glues;
+        let visible = |glues: usize| String::from(glue.repeat(glues).trim_end());
+
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        let mut line_width = 0;
+
+        for (i, &(glues, ref run)) in runs.iter().enumerate() {
+            let run_width: usize = run.iter().map(|elem| elem.chars().count()).sum();
+            let glues_width = glues * glue.chars().count();
+            let end = runs.get(i + 1).map_or(trailing, |&(next, _)| next);
+            let end_width = visible(end).chars().count();
+
+            if line_width + glues_width + run_width + end_width <= width {
+                line.push_str(&glue.repeat(glues));
+                line_width += glues_width;
+            } else if i != 0 {
+                let visible = visible(glues);
+                if line_width + visible.chars().count() <= width {
+                    line.push_str(&visible);
+                }
+                lines.push(line);
+                line = String::new();
+                line_width = 0;
+            }
+            line.extend(run.iter().cloned());
+            line_width += run_width;
+        }
+
+        if !runs.is_empty() {
+            let visible = visible(trailing);
+            if line_width + visible.chars().count() <= width {
+                line.push_str(&visible);
+            }
+            lines.push(line);
+        }
+        lines
+    }
+
+    /// Alternate the elements of both welders, starting with the elements of this one,
+    /// the remaining elements of the longest welder are added at the end.
+    ///
+    /// The elements are glued with the glue of this welder,
+    /// the glues of both welders are not kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let names = BorrowedWelder::with_start(" | ", "name").elems(vec!["age", "city"]);
+    /// let values = BorrowedWelder::with_start(", ", "Kero").elem("27");
+    ///
+    /// let welder = names.interleave(values);
+    ///
+    /// assert_eq!("name | Kero | age | 27 | city", &welder.weld());
+    /// ```
+    pub fn interleave(self, other: BorrowedWelder<'a>) -> Self {
+        let mut ours = self.pieces.into_iter().filter_map(elem_only);
+        let mut theirs = other.pieces.into_iter().filter_map(elem_only);
+
+        let mut welder = BorrowedWelder::new(self.glue);
+        loop {
+            match (ours.next(), theirs.next()) {
+                (None, None) => break,
+                (a, b) => {
+                    for elem in a.into_iter().chain(b) {
+                        welder = if welder.pieces.is_empty() {
+                            welder.elem_no_glue(elem)
+                        } else {
+                            welder.elem(elem)
+                        };
+                    }
+                },
+            }
+        }
+        welder
+    }
+
+    /// This function will add the element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(" ", "foo");
+    ///
+    /// let welder = welder.elem_no_glue("bar");
+    ///
+    /// assert_eq!("foobar", &welder.weld());
+    /// ```
+    pub fn elem_no_glue(mut self, elem: &'a str) -> Self {
+        self.pieces.push(Piece::Elem(elem));
+        self
+    }
+
+    /// This function will add each element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(" ", "foo");
+    ///
+    /// let welder = welder.elems_no_glue(vec!["bar", "baz"]);
+    ///
+    /// assert_eq!("foobarbaz", &welder.weld());
+    /// ```
+    pub fn elems_no_glue<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.pieces.extend(elems.into_iter().map(Piece::Elem));
+        self
+    }
+
+    /// Push a new value to the already accumulated values.
+    /// This function will add a glue element in front of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// let welder = welder.elem("foo").elem("bar");
+    ///
+    /// assert_eq!(" foo bar", &welder.weld());
+    /// ```
+    pub fn elem(self, elem: &'a str) -> Self {
+        self.elem_glue_left(elem)
+    }
+
+    /// Push all elements to the already accumulated values.
+    /// This function will add a glue in front of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// let welder = welder.elems(vec!["foo", "bar"]);
+    ///
+    /// assert_eq!(" foo bar", &welder.weld());
+    /// ```
+    pub fn elems<I>(self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        self.elems_glue_left(elems)
+    }
+
+    /// It will add a glue only to right of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(" ", "foo");
+    ///
+    /// let welder = welder.elem_glue_right("bar");
+    ///
+    /// assert_eq!("foobar ", &welder.weld());
+    /// ```
+    pub fn elem_glue_right(mut self, elem: &'a str) -> Self {
+        self.pieces.push(Piece::Elem(elem));
+        self.pieces.push(Piece::Glue);
+        self
+    }
+
+    /// This function will add a glue to the right of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::with_start(" ", "foo");
+    ///
+    /// let welder = welder.elems_glue_right(vec!["bar", "baz"]);
+    ///
+    /// assert_eq!("foobar baz ", &welder.weld());
+    /// ```
+    pub fn elems_glue_right<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        for elem in elems {
+            self = self.elem_glue_right(elem)
+        }
+        self
+    }
+
+    /// This is the default elem function.
+    /// It will add a glue only to the left of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// let welder = welder.elem_glue_left("foo");
+    ///
+    /// assert_eq!(" foo", &welder.weld());
+    /// ```
+    pub fn elem_glue_left(mut self, elem: &'a str) -> Self {
+        self.pieces.push(Piece::Glue);
+        self.pieces.push(Piece::Elem(elem));
+        self
+    }
+
+    /// Push elements to the already accumulated values.
+    /// This function will add a glue in front of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// let welder = welder.elems_glue_left(vec!["foo", "bar"]);
+    ///
+    /// assert_eq!(" foo bar", &welder.weld());
+    /// ```
+    pub fn elems_glue_left<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        for elem in elems {
+            self = self.elem_glue_left(elem)
+        }
+        self
+    }
+
+    /// This function will add a glue on both sides of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// let welder = welder.elem_glue_both("foo");
+    ///
+    /// assert_eq!(" foo ", &welder.weld());
+    /// ```
+    pub fn elem_glue_both(mut self, elem: &'a str) -> Self {
+        self.pieces.push(Piece::Glue);
+        self.pieces.push(Piece::Elem(elem));
+        self.pieces.push(Piece::Glue);
+        self
+    }
+
+    /// This function will add a glue on both sides of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::BorrowedWelder;
+    ///
+    /// let welder = BorrowedWelder::new(" ");
+    ///
+    /// let welder = welder.elems_glue_both(vec!["foo", "bar"]);
+    ///
+    /// assert_eq!(" foo  bar ", &welder.weld());
+    /// ```
+    pub fn elems_glue_both<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = &'a str>,
+    {
+        for elem in elems {
+            self = self.elem_glue_both(elem)
+        }
+        self
+    }
+}
+
+fn elem_only(piece: Piece<'_>) -> Option<&str> {
+    match piece {
+        Piece::Glue => None,
+        Piece::Elem(elem) => Some(elem),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BorrowedWelder;
+
+    #[test]
+    fn borrowed_welder_exact_capacity() {
+        let owned = String::from("baz");
+        let string = BorrowedWelder::with_start(", ", "foo")
+                                .elem("bar")
+                                .elem(&owned)
+                                .weld();
+
+        assert_eq!("foo, bar, baz", &string);
+        assert_eq!(string.len(), string.capacity());
+    }
+
+    #[test]
+    fn borrowed_welder_empty() {
+        let string = BorrowedWelder::new(", ").weld();
+
+        assert_eq!("", &string);
+        assert_eq!(0, string.capacity());
+    }
+
+    #[test]
+    fn borrowed_welder_lines_too_wide_elements() {
+        let welder = BorrowedWelder::with_start(" ", "a").elems(vec!["abcdefgh", "b", "c"]);
+
+        assert_eq!(vec!["a", "abcdefgh", "b c"], welder.weld_lines(4));
+    }
+
+    #[test]
+    fn borrowed_welder_lines_fit_the_width() {
+        let elems = ["abc", "d", "ef", "ghi", "j", "kl", "m", "nop"];
+        for width in 3..12 {
+            let welder = BorrowedWelder::with_start(", ", "ab").elems(elems.iter().cloned());
+            let lines = welder.weld_lines(width);
+
+            assert!(lines.iter().all(|line| line.chars().count() <= width), "{:?}", lines);
+            let joined: String = lines.concat().split(',').map(str::trim).collect();
+            assert_eq!("ababcdefghijklmnop", &joined);
+        }
+    }
+
+    #[test]
+    fn borrowed_welder_lines_keep_glue_placement() {
+        let welder = BorrowedWelder::with_start(", ", "a").elem_no_glue("b").elem("c").elem_glue_right("d");
+
+        assert_eq!(vec!["ab, cd,"], welder.weld_lines(80));
+        assert_eq!(vec!["ab,", "cd,"], BorrowedWelder::with_start(", ", "a").elem_no_glue("b")
+                                                             .elem("c").elem_glue_right("d")
+                                                             .weld_lines(4));
+    }
+
+    #[test]
+    fn borrowed_welder_lines_leading_glues() {
+        assert_eq!(vec!["abc", "de"], BorrowedWelder::new(" ").elem("abc").elem("de").weld_lines(3));
+        assert_eq!(vec!["ab"], BorrowedWelder::new(", ").elem("ab").weld_lines(2));
+        assert_eq!(vec![", ab"], BorrowedWelder::new(", ").elem("ab").weld_lines(4));
+
+        let elems = ["abc", "d", "ef", "ghi", "j"];
+        for width in 3..12 {
+            let lines = BorrowedWelder::new(", ").elems(elems.iter().cloned()).weld_lines(width);
+
+            assert!(lines.iter().all(|line| line.chars().count() <= width), "{:?}", lines);
+        }
+    }
+
+    #[test]
+    fn borrowed_welder_lines_empty() {
+        assert!(BorrowedWelder::new(" ").weld_lines(80).is_empty());
+    }
+
+    #[test]
+    fn borrowed_welder_interleave_keeps_empty_elements() {
+        let keys = BorrowedWelder::new("-").elems(vec!["a", "", "c"]);
+        let values = BorrowedWelder::new("-").elems(vec!["1", "2"]);
+
+        assert_eq!("a-1--2-c", &keys.interleave(values).weld());
+    }
+}
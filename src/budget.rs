@@ -0,0 +1,124 @@
+use {Glue, Welder};
+
+/// A `Welder` that puts its glue between the elements as long as the current line
+/// fits in a width budget, and an alternate glue, usually containing a newline,
+/// when the next element would exceed it.
+///
+/// It is created by the `width_budget` method of a `Welder`,
+/// the width is counted in characters since the last newline.
+///
+/// The glue is measured on a clone of it before deciding which glue to use,
+/// only the glue that ends up in the output is applied.
+pub struct Budgeted<G, W> {
+    welder: Welder<G, String>,
+    width: usize,
+    wrap: W,
+    probe: String,
+}
+
+impl<G> Welder<G, String> {
+    /// Use the alternate glue in front of the elements that would make
+    /// the current line wider than `width` characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "call(alpha");
+    ///
+    /// let welder = welder.width_budget(20, ",\n     ").elems(vec!["beta", "gamma", "delta"]);
+    ///
+    /// assert_eq!("call(alpha, beta,\n     gamma, delta", &welder.weld());
+    /// ```
+    pub fn width_budget<W>(self, width: usize, wrap: W) -> Budgeted<G, W> {
+        Budgeted { welder: self, width, wrap, probe: String::new() }
+    }
+}
+
+fn line_width(s: &str) -> usize {
+    let start = s.rfind('\n').map_or(0, |i| i + 1);
+    s[start..].chars().count()
+}
+
+impl<G, W> Budgeted<G, W> {
+    /// Retrieve the `Welder`, the width budget is not taken into account anymore.
+    pub fn into_inner(self) -> Welder<G, String> {
+        self.welder
+    }
+
+    /// Retrieve the accumulated values.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+
+    /// Add the element without any glue, see `Welder::elem_no_glue`.
+    pub fn elem_no_glue<E: AsRef<str>>(self, elem: E) -> Self {
+        Budgeted { welder: self.welder.elem_no_glue(elem.as_ref()), ..self }
+    }
+}
+
+impl<G, W> Budgeted<G, W>
+where
+    G: Glue<String> + Clone,
+    W: Glue<String>,
+{
+    /// Add the element with the glue in front of it, or with the alternate glue
+    /// if the element does not fit on the current line anymore.
+    ///
+    /// The alternate glue is not used if the current line is empty,
+    /// an element wider than the budget is then alone on its line.
+    pub fn elem<E: AsRef<str>>(mut self, elem: E) -> Self {
+        let elem = elem.as_ref();
+        let current = line_width(&self.welder.welded);
+
+        self.probe.clear();
+        self.welder.glue.clone().apply(&mut self.probe);
+        let width = current + self.probe.chars().count() + elem.chars().count();
+        let wraps = !self.probe.contains('\n') && width > self.width;
+
+        if wraps && current != 0 {
+            self.wrap.apply(&mut self.welder.welded);
+        } else {
+            self.welder.glue.apply(&mut self.welder.welded);
+        }
+        self.welder.welded.push_str(elem);
+        self.welder.glues += 1;
+        self.welder.count_elems(1);
+        self
+    }
+
+    /// Add each element, see `Budgeted::elem`.
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {IndexedGlue, Welder};
+
+    #[test]
+    fn width_budget_wide_element_alone() {
+        let welder: Welder<_, String> = Welder::new(' ');
+        let welder = welder.width_budget(4, '\n').elems(vec!["a", "bbbbbb", "c", "d"]);
+
+        assert_eq!(" a\nbbbbbb\nc d", &welder.weld());
+    }
+
+    #[test]
+    fn width_budget_only_applies_the_glue_used() {
+        let glue = IndexedGlue::new(|i| if i == 0 { "; " } else { ", " });
+        let welder: Welder<_, String> = Welder::with_start(glue, "aaaa");
+        let welder = welder.width_budget(6, '\n').elems(vec!["bbbb", "c", "dd"]);
+
+        assert_eq!("aaaa\nbbbb\nc; dd", &welder.weld());
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::VecDeque;
+
+use {Glue, Weldable, Welder};
+
+/// A target that can allocate room for additional values up front.
+pub trait Capacity {
+    /// Create an empty target with room for at least `capacity` values.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Make room for at least `additional` more values.
+    fn reserve(&mut self, additional: usize);
+
+    /// The number of values in the target.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the target contains no value.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Capacity for String {
+    fn with_capacity(capacity: usize) -> Self {
+        String::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        String::reserve(self, additional)
+    }
+
+    fn len(&self) -> usize {
+        String::len(self)
+    }
+}
+
+impl<E> Capacity for Vec<E> {
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        Vec::reserve(self, additional)
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl<E> Capacity for VecDeque<E> {
+    fn with_capacity(capacity: usize) -> Self {
+        VecDeque::with_capacity(capacity)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        VecDeque::reserve(self, additional)
+    }
+
+    fn len(&self) -> usize {
+        VecDeque::len(self)
+    }
+}
+
+/// A target that can be cut back to a previous length,
+/// `Welder`s rely on it to discard what was added since a `Snapshot`.
+pub trait Truncate: Capacity {
+    /// Shorten the target to `len` values, nothing happens if it is already shorter.
+    fn truncate(&mut self, len: usize);
+}
+
+impl Truncate for String {
+    fn truncate(&mut self, len: usize) {
+        String::truncate(self, len)
+    }
+}
+
+impl<E> Truncate for Vec<E> {
+    fn truncate(&mut self, len: usize) {
+        Vec::truncate(self, len)
+    }
+}
+
+impl<E> Truncate for VecDeque<E> {
+    fn truncate(&mut self, len: usize) {
+        VecDeque::truncate(self, len)
+    }
+}
+
+impl<G, T: Capacity> Welder<G, T> {
+    /// Create an empty `Welder` whose target has room for at least `capacity` values,
+    /// bytes for a `String` and items for a `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_capacity(", ", 64);
+    ///
+    /// let string = welder.elem_no_glue("foo").elem("bar").weld();
+    ///
+    /// assert_eq!("foo, bar", &string);
+    /// assert!(string.capacity() >= 64);
+    /// ```
+    pub fn with_capacity(glue: G, capacity: usize) -> Self {
+        Welder {
+            glue,
+            welded: T::with_capacity(capacity),
+            delta_start: 0,
+            elems: 0,
+            glues: 0,
+            chunk_open: false,
+        }
+    }
+
+    /// Make room for at least `additional` more values in the target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, Vec<_>> = Welder::with_start(0, 12);
+    ///
+    /// welder.reserve(100);
+    ///
+    /// assert!(welder.weld().capacity() >= 101);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        self.welded.reserve(additional)
+    }
+}
+
+impl<G, T> Welder<G, T>
+where
+    G: Glue<T>,
+    T: Capacity,
+{
+    /// Add each element with a glue in front of it, like `elems`, but make room
+    /// for all of them up front.
+    ///
+    /// The room needed is estimated from the lower bound of the iterator size hint,
+    /// assuming every other element takes as much room as the first one with its glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(", ");
+    ///
+    /// let string = welder.elems_reserved(vec!["foo"; 100]).weld();
+    ///
+    /// assert_eq!(100 * 5, string.len());
+    /// assert!(string.capacity() >= 100 * 5);
+    /// ```
+    pub fn elems_reserved<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        let mut elems = elems.into_iter();
+        let remaining = elems.size_hint().0.saturating_sub(1);
+
+        if let Some(first) = elems.next() {
+            let start = self.welded.len();
+            self.push_elem(first);
+            let room = self.welded.len() - start;
+            self.welded.reserve(remaining.saturating_mul(room));
+        }
+
+        self.push_elems(elems);
+        self
+    }
+
+    /// Add the element `n` times with a glue in front of each repetition,
+    /// like `elems` does.
+    ///
+    /// The room needed is reserved once the first repetition is added,
+    /// the last repetition is the element given.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "id");
+    /// assert_eq!("id, ?, ?", &welder.elem_repeat("?", 2).weld());
+    ///
+    /// let welder: Welder<_, String> = Welder::new(", ");
+    /// assert_eq!(", ?, ?, ?", &welder.elem_repeat("?", 3).weld());
+    /// ```
+    pub fn elem_repeat<E>(mut self, elem: E, n: usize) -> Self
+    where
+        E: Clone,
+        T: Weldable<E>,
+    {
+        if n == 0 {
+            return self
+        }
+
+        if n > 1 {
+            let start = self.welded.len();
+            self.push_elem(elem.clone());
+            let room = self.welded.len() - start;
+            self.welded.reserve((n - 1).saturating_mul(room));
+            for _ in 2..n {
+                self.push_elem(elem.clone());
+            }
+        }
+        self.push_elem(elem);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    #[test]
+    fn elems_reserved_empty_and_unknown_size() {
+        let welder: Welder<_, Vec<_>> = Welder::new(0);
+        assert!(welder.elems_reserved(Vec::<i32>::new()).weld().is_empty());
+
+        let welder: Welder<_, Vec<_>> = Welder::new(0);
+        let vec = welder.elems_reserved((1..4).filter(|_| true)).weld();
+        assert_eq!(vec![0, 1, 0, 2, 0, 3], vec);
+    }
+
+    #[test]
+    fn elem_repeat_glues_like_elems() {
+        let repeated: Welder<_, String> = Welder::from_parts(", ", String::from("?")).elem_repeat("?", 2);
+        let listed: Welder<_, String> = Welder::from_parts(", ", String::from("?")).elems(vec!["?"; 2]);
+
+        assert_eq!(3, repeated.len());
+        assert_eq!(listed.weld(), repeated.weld());
+    }
+}
@@ -0,0 +1,110 @@
+use {Glue, Weldable, Welder};
+
+/// A `Welder` that skips an element identical to the one added just before it.
+///
+/// It is created by the `dedup_adjacent` method of a `Welder`.
+pub struct Deduped<G, T, E> {
+    welder: Welder<G, T>,
+    last: Option<E>,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Weld consecutive identical elements only once.
+    ///
+    /// Only the elements added after this call are deduplicated,
+    /// the elements already in the `Welder` are never compared with the next ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::new(' ').dedup_adjacent();
+    ///
+    /// let welder = welder.elem_no_glue("warn").elems(vec!["warn", "warn", "disk", "warn"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("warn disk warn", &string);
+    /// ```
+    pub fn dedup_adjacent<E>(self) -> Deduped<G, T, E> {
+        Deduped { welder: self, last: None }
+    }
+}
+
+impl<G, T, E> Deduped<G, T, E> {
+    /// Retrieve the `Welder`, the next elements will not be deduplicated anymore.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Retrieve the accumulated values, see `Welder::weld`.
+    pub fn weld(self) -> T {
+        self.welder.weld()
+    }
+}
+
+impl<G, T, E> Deduped<G, T, E>
+where
+    E: Clone + PartialEq,
+    T: Weldable<E>,
+{
+    /// Add the element without any glue, see `Welder::elem_no_glue`,
+    /// nothing is added if it is identical to the previous element.
+    pub fn elem_no_glue(mut self, elem: E) -> Self {
+        if self.last.as_ref() != Some(&elem) {
+            self.welder.push_elem_no_glue(elem.clone());
+            self.last = Some(elem);
+        }
+        self
+    }
+}
+
+impl<G, T, E> Deduped<G, T, E>
+where
+    G: Glue<T>,
+    E: Clone + PartialEq,
+    T: Weldable<E>,
+{
+    /// Add the element with a glue in front of it, see `Welder::elem`,
+    /// nothing is added if it is identical to the previous element.
+    pub fn elem(mut self, elem: E) -> Self {
+        if self.last.as_ref() != Some(&elem) {
+            self.welder.push_elem(elem.clone());
+            self.last = Some(elem);
+        }
+        self
+    }
+
+    /// Add each element with a glue in front of it, see `Welder::elems`,
+    /// elements identical to the previous ones are skipped.
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    #[test]
+    fn dedup_adjacent_ignores_the_elements_already_welded() {
+        let welder = Welder::with_start(0, 1).dedup_adjacent();
+        let vec: Vec<_> = welder.elems(vec![1, 2, 2, 1, 1, 3]).weld();
+
+        assert_eq!(vec![1, 0, 1, 0, 2, 0, 1, 0, 3], vec);
+    }
+
+    #[test]
+    fn dedup_adjacent_only_consecutive() {
+        let welder = Welder::new(0).dedup_adjacent();
+        let vec: Vec<_> = welder.elems(vec![1, 1, 2, 2, 1, 3, 3]).weld();
+
+        assert_eq!(vec![0, 1, 0, 2, 0, 1, 0, 3], vec);
+    }
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use {Glue, Weldable, Welder};
+
+/// An element that can be empty, empty elements are skipped
+/// by the `elem_nonempty` and `elems_nonempty` methods of a `Welder`.
+pub trait IsEmpty {
+    /// Returns `true` if the element contains nothing.
+    fn is_empty(&self) -> bool;
+}
+
+impl IsEmpty for str {
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl IsEmpty for String {
+    fn is_empty(&self) -> bool {
+        String::is_empty(self)
+    }
+}
+
+impl<'a> IsEmpty for Cow<'a, str> {
+    fn is_empty(&self) -> bool {
+        str::is_empty(self)
+    }
+}
+
+impl<E> IsEmpty for [E] {
+    fn is_empty(&self) -> bool {
+        <[E]>::is_empty(self)
+    }
+}
+
+impl<E> IsEmpty for Vec<E> {
+    fn is_empty(&self) -> bool {
+        Vec::is_empty(self)
+    }
+}
+
+impl<E: IsEmpty + ?Sized> IsEmpty for &E {
+    fn is_empty(&self) -> bool {
+        E::is_empty(self)
+    }
+}
+
+impl<G, T> Welder<G, T>
+where
+    G: Glue<T>,
+{
+    /// Add the element with a glue in front of it, or nothing if the element is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(',', "a");
+    ///
+    /// let welder = welder.elem_nonempty("").elem_nonempty("b");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("a,b", &string);
+    /// ```
+    pub fn elem_nonempty<E>(mut self, elem: E) -> Self
+    where
+        E: IsEmpty,
+        T: Weldable<E>,
+    {
+        if !elem.is_empty() {
+            self.push_elem(elem);
+        }
+        self
+    }
+
+    /// Add each element with a glue in front of it, empty elements are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let fields = vec![String::from("a"), String::new(), String::from("b")];
+    ///
+    /// let welder = Welder::new(',').elem_no_glue("fields:").elems_nonempty(fields);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("fields:,a,b", &string);
+    /// ```
+    pub fn elems_nonempty<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: IsEmpty,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            if !elem.is_empty() {
+                self.push_elem(elem);
+            }
+        }
+        self
+    }
+}
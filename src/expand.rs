@@ -0,0 +1,182 @@
+use std::env;
+
+use {Glue, Welder};
+
+/// A `Welder` that expands the `${VAR}` and `$VAR` references of the elements it is given.
+///
+/// It is created by the `expand_vars` or the `expand_env` methods of a `Welder`,
+/// the variable names are made of ASCII letters, digits and underscores and do not
+/// start with a digit. Unknown variables are expanded to nothing, a `$` that does
+/// not start a reference is kept as is. The glue is never expanded.
+pub struct Expanded<G, F> {
+    welder: Welder<G, String>,
+    lookup: F,
+}
+
+impl<G> Welder<G, String> {
+    /// Expand the variable references of every element added to the returned welder,
+    /// using the lookup function to retrieve the value of a variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new('/');
+    ///
+    /// let lookup = |name: &str| match name {
+    ///     "HOME" => Some(String::from("/home/kero")),
+    ///     _ => None,
+    /// };
+    /// let welder = welder.expand_vars(lookup).elem_no_glue("$HOME").elem("${APP}cache");
+    ///
+    /// assert_eq!("/home/kero/cache", &welder.weld());
+    /// ```
+    pub fn expand_vars<F>(self, lookup: F) -> Expanded<G, F>
+    where
+        F: FnMut(&str) -> Option<String>,
+    {
+        Expanded { welder: self, lookup }
+    }
+
+    /// Expand the variable references of every element added to the returned welder
+    /// with the environment variables of the current process.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// std::env::set_var("WELDER_EXPAND_DOC", "release");
+    /// let welder: Welder<_, String> = Welder::with_start('/', "target");
+    ///
+    /// let welder = welder.expand_env().elem("$WELDER_EXPAND_DOC");
+    ///
+    /// assert_eq!("target/release", &welder.weld());
+    /// ```
+    pub fn expand_env(self) -> Expanded<G, fn(&str) -> Option<String>> {
+        fn lookup(name: &str) -> Option<String> {
+            env::var(name).ok()
+        }
+        self.expand_vars(lookup)
+    }
+}
+
+fn is_name_start(c: char) -> bool {
+    c.is_ascii_alphabetic() || c == '_'
+}
+
+fn is_name(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn expand<F>(elem: &str, mut lookup: F) -> String
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    let mut expanded = String::with_capacity(elem.len());
+    let mut rest = elem;
+
+    while let Some(i) = rest.find('$') {
+        expanded.push_str(&rest[..i]);
+        let after = &rest[i + 1..];
+
+        let (name, next) = if let Some(braced) = after.strip_prefix('{') {
+            match braced.find('}') {
+                Some(end) if braced[..end].starts_with(is_name_start) && braced[..end].chars().all(is_name) => {
+                    (&braced[..end], &braced[end + 1..])
+                },
+                _ => ("", after),
+            }
+        } else if after.starts_with(is_name_start) {
+            let end = after.find(|c| !is_name(c)).unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        } else {
+            ("", after)
+        };
+
+        if name.is_empty() {
+            expanded.push('$');
+        } else if let Some(value) = lookup(name) {
+            expanded.push_str(&value);
+        }
+        rest = next;
+    }
+
+    expanded.push_str(rest);
+    expanded
+}
+
+impl<G, F> Expanded<G, F>
+where
+    F: FnMut(&str) -> Option<String>,
+{
+    /// Retrieve the accumulated values.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+
+    /// Retrieve the underlying `Welder`, removing the expansion.
+    pub fn into_inner(self) -> Welder<G, String> {
+        self.welder
+    }
+
+    /// Expand then add the element without any glue.
+    pub fn elem_no_glue<E: AsRef<str>>(mut self, elem: E) -> Self {
+        let elem = expand(elem.as_ref(), &mut self.lookup);
+        Expanded { welder: self.welder.elem_no_glue(elem), ..self }
+    }
+}
+
+impl<G, F> Expanded<G, F>
+where
+    G: Glue<String>,
+    F: FnMut(&str) -> Option<String>,
+{
+    /// Expand then add the element with a glue in front of it.
+    pub fn elem<E: AsRef<str>>(mut self, elem: E) -> Self {
+        let elem = expand(elem.as_ref(), &mut self.lookup);
+        Expanded { welder: self.welder.elem::<String>(elem), ..self }
+    }
+
+    /// Expand then add each element with a glue in front of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "cc");
+    ///
+    /// let welder = welder.expand_vars(|_| Some(String::from("-O2")));
+    /// let welder = welder.elems(vec!["$CFLAGS", "main.c", "costs $5"]);
+    ///
+    /// assert_eq!("cc -O2 main.c costs $5", &welder.weld());
+    /// ```
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::expand;
+
+    #[test]
+    fn expand_references() {
+        let lookup = |name: &str| if name == "A" { Some(String::from("x")) } else { None };
+
+        assert_eq!("x-x-", &expand("$A-${A}-$B", lookup));
+        assert_eq!("${A", &expand("${A", lookup));
+        assert_eq!("${1}$", &expand("${1}$", lookup));
+        assert_eq!("xB", &expand("${A}B", lookup));
+        assert_eq!("", &expand("$AB", lookup));
+    }
+}
@@ -0,0 +1,178 @@
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use Welder;
+
+static TEMP_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The directory the destination is in, the current one for a relative file name.
+fn parent_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    }
+}
+
+/// Create a new temporary file next to the destination,
+/// so that renaming it over the destination never crosses a filesystem.
+fn create_temp_next_to(path: &Path) -> io::Result<(File, PathBuf)> {
+    let dir = parent_dir(path);
+    let name = path.file_name().ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidInput, "the destination path has no file name")
+    })?;
+
+    loop {
+        let count = TEMP_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let mut temp_name = name.to_os_string();
+        temp_name.push(format!(".{}.{}.tmp", process::id(), count));
+        let temp_path = dir.join(temp_name);
+
+        match OpenOptions::new().write(true).create_new(true).open(&temp_path) {
+            Ok(file) => return Ok((file, temp_path)),
+            Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Make the rename of a file in the directory durable.
+#[cfg(unix)]
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    File::open(dir)?.sync_all()
+}
+
+#[cfg(not(unix))]
+fn sync_dir(_dir: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Give the file the permissions of the destination, if it already exists.
+fn copy_permissions(path: &Path, file: &File) -> io::Result<()> {
+    match fs::metadata(path) {
+        Ok(metadata) => file.set_permissions(metadata.permissions()),
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn write_atomically(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let (mut file, temp_path) = create_temp_next_to(path)?;
+
+    // the permissions are copied before anything is written to the temporary file
+    let result = copy_permissions(path, &file)
+        .and_then(|_| file.write_all(bytes))
+        .and_then(|_| file.sync_all())
+        .and_then(|_| fs::rename(&temp_path, path));
+
+    if result.is_err() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result.and_then(|_| sync_dir(parent_dir(path)))
+}
+
+impl<G> Welder<G, String> {
+    /// Write the accumulated values to a temporary file in the same directory
+    /// as the destination, then rename it over the destination.
+    ///
+    /// The destination is never observed half-written, it is either
+    /// left untouched or replaced by the whole output, keeping its permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs;
+    /// use welder::Welder;
+    ///
+    /// # let path = std::env::temp_dir().join("welder-weld-to-path-doc.conf");
+    /// let welder: Welder<_, String> = Welder::new('\n');
+    /// let welder = welder.elems_glue_right(vec!["port = 80", "host = localhost"]);
+    ///
+    /// welder.weld_to_path(&path).unwrap();
+    ///
+    /// assert_eq!("port = 80\nhost = localhost\n", fs::read_to_string(&path).unwrap());
+    /// # fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn weld_to_path<P: AsRef<Path>>(self, path: P) -> io::Result<()> {
+        write_atomically(path.as_ref(), self.welded.as_bytes())
+    }
+}
+
+impl<G> Welder<G, Vec<u8>> {
+    /// Write the accumulated bytes to a temporary file in the same directory
+    /// as the destination, then rename it over the destination.
+    ///
+    /// The destination is never observed half-written, it is either
+    /// left untouched or replaced by the whole output, keeping its permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs;
+    /// use welder::Welder;
+    ///
+    /// # let path = std::env::temp_dir().join("welder-weld-to-path-doc.bin");
+    /// let welder: Welder<_, Vec<u8>> = Welder::with_start(0, 1).elem(2);
+    ///
+    /// welder.weld_to_path(&path).unwrap();
+    ///
+    /// assert_eq!(vec![1, 0, 2], fs::read(&path).unwrap());
+    /// # fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn weld_to_path<P: AsRef<Path>>(self, path: P) -> io::Result<()> {
+        write_atomically(path.as_ref(), &self.welded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use Welder;
+
+    #[test]
+    fn weld_to_path_replaces_and_cleans_up() {
+        let dir = env::temp_dir().join(format!("welder-file-test-{}", ::std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("output.txt");
+        fs::write(&path, "old content").unwrap();
+
+        let welder: Welder<_, String> = Welder::with_start(' ', "new").elem("content");
+        welder.weld_to_path(&path).unwrap();
+
+        assert_eq!("new content", fs::read_to_string(&path).unwrap());
+        assert_eq!(1, fs::read_dir(&dir).unwrap().count());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn weld_to_path_keeps_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = env::temp_dir().join(format!("welder-file-perms-{}", ::std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("secret.txt");
+        fs::write(&path, "old").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o600)).unwrap();
+
+        let welder: Welder<_, String> = Welder::with_start(' ', "new");
+        welder.weld_to_path(&path).unwrap();
+
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(0o600, mode & 0o777);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn weld_to_path_missing_directory() {
+        let path = env::temp_dir().join("welder-missing-dir").join("output.txt");
+
+        let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+
+        assert!(welder.weld_to_path(&path).is_err());
+        assert!(!path.exists());
+    }
+}
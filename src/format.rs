@@ -0,0 +1,147 @@
+use std::fmt::{self, Write};
+
+use {Glue, Welder};
+
+/// Formats the values accumulated so far, without consuming the `Welder`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::Welder;
+///
+/// let welder: Welder<_, String> = Welder::with_start(", ", "foo").elem("bar");
+///
+/// assert_eq!("[foo, bar]", format!("[{}]", welder));
+/// ```
+impl<G, T: fmt::Display> fmt::Display for Welder<G, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.welded.fmt(f)
+    }
+}
+
+/// Every `write_str` call, and every `write!` call as a whole,
+/// is added as one element with a glue in front of it.
+///
+/// # Examples
+///
+/// ```
+/// use std::fmt::Write;
+/// use welder::Welder;
+///
+/// let mut welder: Welder<_, String> = Welder::with_start(';', "start");
+///
+/// write!(welder, "x={}, y={}", 1, 2).unwrap();
+/// welder.write_str("end").unwrap();
+///
+/// assert_eq!("start;x=1, y=2;end", &welder.weld());
+/// ```
+impl<G: Glue<String>> Write for Welder<G, String> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_elem(s);
+        Ok(())
+    }
+
+    fn write_fmt(&mut self, args: fmt::Arguments) -> fmt::Result {
+        self.glue.apply(&mut self.welded);
+        self.glues += 1;
+        self.count_elems(1);
+        self.welded.write_fmt(args)
+    }
+}
+
+impl<G: Glue<String>> Welder<G, String> {
+    /// Add the element with a glue in front of it, the element is formatted
+    /// directly into the accumulated `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "cp");
+    ///
+    /// let welder = welder.elem_display(Path::new("a.txt").display()).elem_display(42);
+    ///
+    /// assert_eq!("cp a.txt 42", &welder.weld());
+    /// ```
+    pub fn elem_display<E: fmt::Display>(mut self, elem: E) -> Self {
+        self.glue.apply(&mut self.welded);
+        self.glues += 1;
+        self.count_elems(1);
+        // writing into a String never fails
+        let _ = write!(self.welded, "{}", elem);
+        self
+    }
+
+    /// Add each element with a glue in front of it, the elements are formatted
+    /// directly into the accumulated `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "values:");
+    ///
+    /// let welder = welder.elems_display(vec![1.5, 2.25]);
+    ///
+    /// assert_eq!("values:, 1.5, 2.25", &welder.weld());
+    /// ```
+    pub fn elems_display<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        for elem in elems {
+            self = self.elem_display(elem)
+        }
+        self
+    }
+
+    /// Add the formatted arguments with a glue in front of them,
+    /// they are written directly into the accumulated `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new('&');
+    ///
+    /// let welder = welder.elem_fmt(format_args!("{}={}", "page", 2))
+    ///                    .elem_fmt(format_args!("{}={:.1}", "ratio", 0.25));
+    ///
+    /// assert_eq!("&page=2&ratio=0.2", &welder.weld());
+    /// ```
+    pub fn elem_fmt(mut self, args: fmt::Arguments) -> Self {
+        self.glue.apply(&mut self.welded);
+        self.glues += 1;
+        self.count_elems(1);
+        // writing into a String never fails
+        let _ = self.welded.write_fmt(args);
+        self
+    }
+
+    /// Add the formatted arguments without a glue in front of them,
+    /// they are written directly into the accumulated `String`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(", ");
+    ///
+    /// let welder = welder.elem_fmt_no_glue(format_args!("x={}", 1))
+    ///                    .elem_fmt(format_args!("y={}", 2));
+    ///
+    /// assert_eq!("x=1, y=2", &welder.weld());
+    /// ```
+    pub fn elem_fmt_no_glue(mut self, args: fmt::Arguments) -> Self {
+        self.count_elems(1);
+        // writing into a String never fails
+        let _ = self.welded.write_fmt(args);
+        self
+    }
+}
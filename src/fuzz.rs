@@ -0,0 +1,151 @@
+//! Support of the `arbitrary` crate to fuzz the welders.
+//!
+//! The `Insertion` type describes every way an element can be added to a welder,
+//! a fuzzer can generate sequences of them and check that the different welders agree.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use {BorrowedWelder, ControlChars, Glue, Unwelder, Weldable, Welder};
+
+/// One of the ways an element can be added to a welder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Insertion<E> {
+    /// Add the element without any glue, like `elem_no_glue`.
+    NoGlue(E),
+    /// Add the element with a glue to its left, like `elem`.
+    GlueLeft(E),
+    /// Add the element with a glue to its right, like `elem_glue_right`.
+    GlueRight(E),
+    /// Add the element with a glue on both sides, like `elem_glue_both`.
+    GlueBoth(E),
+}
+
+impl<E> Insertion<E> {
+    /// Add the element to the welder the way this insertion describes.
+    pub fn apply<G, T>(self, welder: Welder<G, T>) -> Welder<G, T>
+    where
+        G: Glue<T>,
+        T: Weldable<E>,
+    {
+        match self {
+            Insertion::NoGlue(elem) => welder.elem_no_glue(elem),
+            Insertion::GlueLeft(elem) => welder.elem_glue_left(elem),
+            Insertion::GlueRight(elem) => welder.elem_glue_right(elem),
+            Insertion::GlueBoth(elem) => welder.elem_glue_both(elem),
+        }
+    }
+}
+
+impl<'a> Insertion<&'a str> {
+    /// Add the element to the borrowed welder the way this insertion describes.
+    pub fn apply_borrowed(self, welder: BorrowedWelder<'a>) -> BorrowedWelder<'a> {
+        match self {
+            Insertion::NoGlue(elem) => welder.elem_no_glue(elem),
+            Insertion::GlueLeft(elem) => welder.elem_glue_left(elem),
+            Insertion::GlueRight(elem) => welder.elem_glue_right(elem),
+            Insertion::GlueBoth(elem) => welder.elem_glue_both(elem),
+        }
+    }
+}
+
+impl<'a, E: Arbitrary<'a>> Arbitrary<'a> for Insertion<E> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let elem = E::arbitrary(u)?;
+        Ok(match u.int_in_range(0u8..=3)? {
+            0 => Insertion::NoGlue(elem),
+            1 => Insertion::GlueLeft(elem),
+            2 => Insertion::GlueRight(elem),
+            _ => Insertion::GlueBoth(elem),
+        })
+    }
+}
+
+/// The accumulated values are built by applying arbitrary insertions,
+/// each element being itself an arbitrary target, so that the counts of
+/// elements and glues always match what the `Welder` contains.
+impl<'a, G, T> Arbitrary<'a> for Welder<G, T>
+where
+    G: Arbitrary<'a> + Glue<T>,
+    T: Arbitrary<'a> + Default + Weldable<T>,
+{
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut welder = Welder::new(G::arbitrary(u)?);
+        for insertion in u.arbitrary_iter::<Insertion<T>>()? {
+            welder = insertion?.apply(welder);
+        }
+        Ok(welder)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BorrowedWelder<'a> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut welder = BorrowedWelder::new(<&str>::arbitrary(u)?);
+        for insertion in u.arbitrary_iter::<Insertion<&'a str>>()? {
+            welder = insertion?.apply_borrowed(welder);
+        }
+        Ok(welder)
+    }
+}
+
+impl<'a, G: Arbitrary<'a>> Arbitrary<'a> for Unwelder<G> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let mut unwelder = Unwelder::new(G::arbitrary(u)?);
+        if u.arbitrary()? {
+            unwelder = unwelder.leading_glue();
+        }
+        if u.arbitrary()? {
+            unwelder = unwelder.trailing_glue();
+        }
+        Ok(unwelder)
+    }
+}
+
+impl<'a> Arbitrary<'a> for ControlChars {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        if u.arbitrary()? {
+            Ok(ControlChars::Replace(u.arbitrary()?))
+        } else {
+            Ok(ControlChars::Strip)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arbitrary::{Arbitrary, Unstructured};
+    use super::Insertion;
+    use {BorrowedWelder, Welder};
+
+    #[test]
+    fn welders_agree_on_arbitrary_insertions() {
+        let data: Vec<u8> = (0..4096u32).map(|i| (i.wrapping_mul(2654435761) >> 13) as u8).collect();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let glue = <&str>::arbitrary(&mut u).unwrap();
+            let insertions = Vec::<Insertion<&str>>::arbitrary(&mut u).unwrap();
+
+            let mut welder: Welder<_, String> = Welder::new(glue);
+            let mut borrowed = BorrowedWelder::new(glue);
+            for insertion in insertions {
+                welder = insertion.apply(welder);
+                borrowed = insertion.apply_borrowed(borrowed);
+            }
+
+            assert_eq!(welder.weld(), borrowed.weld());
+        }
+    }
+
+    #[test]
+    fn arbitrary_welder_counts_its_elements() {
+        let data: Vec<u8> = (0..1024u32).map(|i| (i.wrapping_mul(40503) >> 7) as u8).collect();
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let welder = Welder::<String, String>::arbitrary(&mut u).unwrap();
+            if !welder.as_str().is_empty() {
+                assert!(welder.len() + welder.glue_count() > 0);
+            }
+        }
+    }
+}
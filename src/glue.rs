@@ -0,0 +1,341 @@
+use Weldable;
+
+/// A glue that a `Welder` puts between the elements it accumulates.
+///
+/// It is implemented for the primitive types, `char`, `&str` and `String`,
+/// which push themselves into the target, for slices, arrays and `Vec`s,
+/// which push each of their items, and for closures taking the target,
+/// which are free to push anything into it. Iterators are used as glues
+/// through `GlueIter` and any other type that can be cloned through `CloneGlue`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::Welder;
+///
+/// let welder = Welder::with_start(|s: &mut String| s.push_str(" | "), "foo");
+/// let string: String = welder.elem("bar").weld();
+/// assert_eq!("foo | bar", &string);
+///
+/// let welder = Welder::with_start([0, 0], 1);
+/// let vec: Vec<_> = welder.elem(2).weld();
+/// assert_eq!(&[1, 0, 0, 2], vec.as_slice());
+/// ```
+pub trait Glue<T> {
+    /// Push the glue into the accumulated values.
+    fn apply(&mut self, welded: &mut T);
+
+    /// The number of values applying the glue would add to the target,
+    /// if it is known without applying it.
+    fn len_hint(&self, _welded: &T) -> Option<usize> {
+        None
+    }
+}
+
+macro_rules! impl_glue_value {
+    ($($ty:ty),*) => {
+        $(
+            impl<T: Weldable<$ty>> Glue<T> for $ty {
+                fn apply(&mut self, welded: &mut T) {
+                    welded.weld_elem(*self);
+                }
+
+                fn len_hint(&self, welded: &T) -> Option<usize> {
+                    welded.len_hint(self)
+                }
+            }
+        )*
+    }
+}
+
+impl_glue_value!(bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<'a, T: Weldable<&'a str>> Glue<T> for &'a str {
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elem(*self);
+    }
+
+    fn len_hint(&self, welded: &T) -> Option<usize> {
+        welded.len_hint(self)
+    }
+}
+
+impl<T: Weldable<String>> Glue<T> for String {
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elem(self.clone());
+    }
+
+    fn len_hint(&self, welded: &T) -> Option<usize> {
+        welded.len_hint(self)
+    }
+}
+
+impl<E: Clone, T: Weldable<E>> Glue<T> for &[E] {
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elems(self.iter().cloned());
+    }
+
+    fn len_hint(&self, welded: &T) -> Option<usize> {
+        self.iter().map(|elem| welded.len_hint(elem)).sum()
+    }
+}
+
+impl<E: Clone, T: Weldable<E>, const N: usize> Glue<T> for [E; N] {
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elems(self.iter().cloned());
+    }
+
+    fn len_hint(&self, welded: &T) -> Option<usize> {
+        self.iter().map(|elem| welded.len_hint(elem)).sum()
+    }
+}
+
+impl<E: Clone, T: Weldable<E>> Glue<T> for Vec<E> {
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elems(self.iter().cloned());
+    }
+
+    fn len_hint(&self, welded: &T) -> Option<usize> {
+        self.iter().map(|elem| welded.len_hint(elem)).sum()
+    }
+}
+
+/// A glue made of every item of an iterator, the iterator is cloned
+/// each time the glue is applied so that all of its items are pushed again.
+///
+/// # Examples
+///
+/// ```
+/// use welder::{Welder, GlueIter};
+///
+/// let glue = GlueIter::new("--".chars());
+/// let welder = Welder::with_start(glue, 'a').elem('b');
+///
+/// let vec: Vec<char> = welder.weld();
+/// assert_eq!(vec!['a', '-', '-', 'b'], vec);
+/// ```
+#[derive(Debug, Clone)]
+pub struct GlueIter<I> {
+    iter: I,
+}
+
+impl<I: Iterator + Clone> GlueIter<I> {
+    /// Create a glue from the items of the iterator.
+    pub fn new<J>(iter: J) -> Self
+    where
+        J: IntoIterator<IntoIter = I, Item = I::Item>,
+    {
+        GlueIter { iter: iter.into_iter() }
+    }
+}
+
+impl<I, T> Glue<T> for GlueIter<I>
+where
+    I: Iterator + Clone,
+    T: Weldable<I::Item>,
+{
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elems(self.iter.clone());
+    }
+}
+
+/// A glue computed from its position, the closure is given the number
+/// of glues applied before this one and returns the glue to push.
+///
+/// # Examples
+///
+/// ```
+/// use welder::{Welder, IndexedGlue};
+///
+/// let glue = IndexedGlue::new(|i| if i % 2 == 1 { '\n' } else { ' ' });
+/// let welder = Welder::with_start(glue, 'a').elems(vec!['b', 'c', 'd']);
+///
+/// let string: String = welder.weld();
+/// assert_eq!("a b\nc d", &string);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedGlue<F> {
+    f: F,
+    index: usize,
+}
+
+impl<F> IndexedGlue<F> {
+    /// Create a glue from the closure, the first glue applied has the index 0.
+    pub fn new(f: F) -> Self {
+        IndexedGlue { f, index: 0 }
+    }
+}
+
+impl<F, H, T> Glue<T> for IndexedGlue<F>
+where
+    F: FnMut(usize) -> H,
+    H: Glue<T>,
+{
+    fn apply(&mut self, welded: &mut T) {
+        let mut glue = (self.f)(self.index);
+        self.index += 1;
+        glue.apply(welded);
+    }
+}
+
+/// A glue that cycles through a list of glues, one after the other.
+///
+/// # Examples
+///
+/// ```
+/// use welder::{Welder, CycleGlue};
+///
+/// let glue = CycleGlue::new(vec!['=', '&']);
+/// let welder = Welder::with_start(glue, "a").elems(vec!["1", "b", "2"]);
+///
+/// let string: String = welder.weld();
+/// assert_eq!("a=1&b=2", &string);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CycleGlue<G> {
+    glues: Vec<G>,
+    index: usize,
+}
+
+impl<G> CycleGlue<G> {
+    /// Create a glue that cycles through the given glues.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no glue.
+    pub fn new<I: IntoIterator<Item = G>>(glues: I) -> Self {
+        let glues: Vec<_> = glues.into_iter().collect();
+        assert!(!glues.is_empty(), "at least one glue must be given");
+        CycleGlue { glues, index: 0 }
+    }
+}
+
+impl<G: Glue<T>, T> Glue<T> for CycleGlue<G> {
+    fn apply(&mut self, welded: &mut T) {
+        self.glues[self.index].apply(welded);
+        self.index = (self.index + 1) % self.glues.len();
+    }
+}
+
+/// A glue that is applied once before another glue is used for the rest.
+///
+/// # Examples
+///
+/// ```
+/// use welder::{Welder, FirstGlue};
+///
+/// let glue = FirstGlue::new(": ", ", ");
+/// let welder = Welder::with_start(glue, "fruits").elems(vec!["apple", "pear"]);
+///
+/// let string: String = welder.weld();
+/// assert_eq!("fruits: apple, pear", &string);
+/// ```
+#[derive(Debug, Clone)]
+pub struct FirstGlue<F, G> {
+    first: Option<F>,
+    rest: G,
+}
+
+impl<F, G> FirstGlue<F, G> {
+    /// Create a glue that applies `first` the first time and `rest` afterwards.
+    pub fn new(first: F, rest: G) -> Self {
+        FirstGlue { first: Some(first), rest }
+    }
+}
+
+impl<F: Glue<T>, G: Glue<T>, T> Glue<T> for FirstGlue<F, G> {
+    fn apply(&mut self, welded: &mut T) {
+        match self.first.take() {
+            Some(mut first) => first.apply(welded),
+            None => self.rest.apply(welded),
+        }
+    }
+}
+
+/// A glue of any type that can be cloned, a clone of it is welded
+/// into the target each time it is applied.
+///
+/// This is how glues of a user type, that have no `Glue` implementation,
+/// are used with a `Welder`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::{Welder, CloneGlue};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Token { Word(&'static str), Sep }
+///
+/// let welder = Welder::with_start(CloneGlue::new(Token::Sep), Token::Word("a"));
+///
+/// let tokens: Vec<_> = welder.elem(Token::Word("b")).weld();
+/// assert_eq!(vec![Token::Word("a"), Token::Sep, Token::Word("b")], tokens);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CloneGlue<G> {
+    glue: G,
+}
+
+impl<G> CloneGlue<G> {
+    /// Create a glue that welds clones of `glue`.
+    pub fn new(glue: G) -> Self {
+        CloneGlue { glue }
+    }
+}
+
+impl<G: Clone, T: Weldable<G>> Glue<T> for CloneGlue<G> {
+    fn apply(&mut self, welded: &mut T) {
+        welded.weld_elem(self.glue.clone());
+    }
+
+    fn len_hint(&self, welded: &T) -> Option<usize> {
+        welded.len_hint(&self.glue)
+    }
+}
+
+impl<T, F: FnMut(&mut T)> Glue<T> for F {
+    fn apply(&mut self, welded: &mut T) {
+        self(welded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GlueIter, IndexedGlue};
+    use Welder;
+
+    #[test]
+    fn glue_closure_can_count() {
+        let mut count = 0;
+        let glue = |s: &mut String| { count += 1; s.push_str(&count.to_string()) };
+
+        let string: String = Welder::with_start(glue, "a").elem("b").elem("c").weld();
+
+        assert_eq!("a1b2c", &string);
+    }
+
+    #[test]
+    fn glue_iter_repeats_every_item() {
+        let glue = GlueIter::new((0..3).map(|x| x * 10));
+        let vec: Vec<_> = Welder::with_start(glue, 1).elem(2).elem(3).weld();
+
+        assert_eq!(vec![1, 0, 10, 20, 2, 0, 10, 20, 3], vec);
+    }
+
+    #[test]
+    fn indexed_glue_breaks_lines() {
+        let glue = IndexedGlue::new(|i| if (i + 1) % 5 == 0 { "\n" } else { ", " });
+        let string: String = Welder::new(glue).elem_no_glue("0").elems((1..12).map(|i| i.to_string())).weld();
+
+        assert_eq!("0, 1, 2, 3, 4\n5, 6, 7, 8, 9\n10, 11", &string);
+    }
+
+    #[test]
+    fn glue_string_and_vec() {
+        let string: String = Welder::with_start(String::from(", "), "a").elem("b").weld();
+        assert_eq!("a, b", &string);
+
+        let vec: Vec<char> = Welder::with_start(vec!['-', '-'], 'a').elem('b').weld();
+        assert_eq!(vec!['a', '-', '-', 'b'], vec);
+    }
+}
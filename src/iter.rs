@@ -0,0 +1,30 @@
+use {Glue, Weldable, Welder};
+
+/// An extension trait to weld the items of any iterator.
+pub trait WeldIterator: Iterator + Sized {
+    /// Weld all the items of the iterator with the glue between them,
+    /// see `Welder::from_iter`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::WeldIterator;
+    ///
+    /// let names = vec!["Alice", "Bob", "Carol"];
+    ///
+    /// let string: String = names.iter().weld_with(", ");
+    /// assert_eq!("Alice, Bob, Carol", &string);
+    ///
+    /// let vec: Vec<_> = (1..4).weld_with(0);
+    /// assert_eq!(&[1, 0, 2, 0, 3], vec.as_slice());
+    /// ```
+    fn weld_with<G, T>(self, glue: G) -> T
+    where
+        G: Glue<T>,
+        T: Default + Weldable<Self::Item>,
+    {
+        Welder::from_iter(glue, self).weld()
+    }
+}
+
+impl<I: Iterator> WeldIterator for I {}
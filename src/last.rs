@@ -0,0 +1,102 @@
+use {Glue, Weldable, Welder};
+
+/// A `Welder` that uses another glue in front of the last element,
+/// the last element is kept aside until the output is welded.
+///
+/// It is created by the `final_glue` method of a `Welder`.
+pub struct FinalGlued<G, T, F, E> {
+    welder: Welder<G, T>,
+    final_glue: F,
+    pending: Option<E>,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Use the given glue in front of the last element instead of the normal one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::new(", ").final_glue(" and ");
+    ///
+    /// let welder = welder.elem_no_glue("a").elems(vec!["b", "c"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("a, b and c", &string);
+    /// ```
+    pub fn final_glue<F, E>(self, final_glue: F) -> FinalGlued<G, T, F, E> {
+        FinalGlued { welder: self, final_glue, pending: None }
+    }
+}
+
+impl<G, T, F, E> FinalGlued<G, T, F, E>
+where
+    G: Glue<T>,
+    F: Glue<T>,
+    T: Weldable<E>,
+{
+    /// Add the element without any glue, see `Welder::elem_no_glue`.
+    pub fn elem_no_glue(mut self, elem: E) -> Self {
+        if let Some(pending) = self.pending.take() {
+            self.welder = self.welder.elem(pending);
+        }
+        FinalGlued { welder: self.welder.elem_no_glue(elem), ..self }
+    }
+
+    /// Add the element with a glue in front of it, see `Welder::elem`,
+    /// the final glue is used if no other element is added after this one.
+    pub fn elem(mut self, elem: E) -> Self {
+        if let Some(pending) = self.pending.take() {
+            self.welder = self.welder.elem(pending);
+        }
+        FinalGlued { pending: Some(elem), ..self }
+    }
+
+    /// Add each element with a glue in front of it, see `Welder::elems`,
+    /// the final glue is put in front of the last element.
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+
+    /// Retrieve the `Welder`, the element kept aside is added with the normal glue.
+    pub fn into_inner(self) -> Welder<G, T> {
+        match self.pending {
+            Some(pending) => self.welder.elem(pending),
+            None => self.welder,
+        }
+    }
+
+    /// Retrieve the accumulated values, the element kept aside
+    /// is added with the final glue in front of it.
+    pub fn weld(self) -> T {
+        match self.pending {
+            Some(pending) => self.welder.elem_with_glue(self.final_glue, pending).weld(),
+            None => self.welder.weld(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    #[test]
+    fn final_glue_only_before_last() {
+        let welder: Welder<_, String> = Welder::with_start(", ", "a");
+        assert_eq!("a", &welder.final_glue::<_, &str>(" or ").weld());
+
+        let welder: Welder<_, String> = Welder::with_start(", ", "a");
+        assert_eq!("a or b", &welder.final_glue(" or ").elem("b").weld());
+
+        let welder: Welder<_, String> = Welder::with_start(", ", "a");
+        let welder = welder.final_glue(" or ").elems(vec!["b", "c", "d"]).into_inner();
+        assert_eq!("a, b, c, d", &welder.weld());
+    }
+}
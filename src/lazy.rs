@@ -0,0 +1,437 @@
+use std::fmt;
+use std::io::{self, BufWriter, Write};
+use std::mem;
+
+use {Glue, Weldable};
+
+enum Piece<'a, E> {
+    Glue,
+    Elem(Box<dyn FnOnce() -> E + 'a>),
+}
+
+/// A `Welder` that accumulates element producers instead of elements.
+///
+/// Each element is a closure that is only evaluated when `weld` is called,
+/// dropping a `LazyWelder` never evaluates any of them.
+pub struct LazyWelder<'a, G, E> {
+    glue: G,
+    pieces: Vec<Piece<'a, E>>,
+}
+
+impl<'a, G, E> LazyWelder<'a, G, E> {
+    /// Create an empty `LazyWelder` just by defining the glue used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::<_, &str>::new(' ');
+    ///
+    /// let string: String = welder.weld();
+    ///
+    /// assert_eq!("", &string);
+    /// ```
+    pub fn new(glue: G) -> Self {
+        LazyWelder {
+            glue,
+            pieces: Vec::new(),
+        }
+    }
+
+    /// Create a `LazyWelder` with a first element producer and the glue it will use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(' ', || "foo");
+    ///
+    /// let string: String = welder.weld();
+    ///
+    /// assert_eq!("foo", &string);
+    /// ```
+    pub fn with_start<F>(glue: G, start: F) -> Self
+    where
+        F: FnOnce() -> E + 'a,
+    {
+        LazyWelder::new(glue).elem_no_glue(start)
+    }
+
+    /// Evaluate every element producer, in order, and accumulate the results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(", ", || format!("{:?}", Some(1)));
+    ///
+    /// let welder = welder.elem(|| format!("{:?}", [2, 3]));
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("Some(1), [2, 3]", &string);
+    /// ```
+    pub fn weld<T>(mut self) -> T
+    where
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        let mut welded = T::default();
+        for piece in self.pieces {
+            match piece {
+                Piece::Glue => self.glue.apply(&mut welded),
+                Piece::Elem(f) => welded.weld_elem(f()),
+            }
+        }
+        welded
+    }
+
+    /// Evaluate every element producer, in order, then compute the exact length
+    /// of the output to allocate it once and fill it in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(", ", || "foo").elem(|| "bar");
+    ///
+    /// let string = welder.weld_exact();
+    ///
+    /// assert_eq!("foo, bar", &string);
+    /// assert_eq!(string.len(), string.capacity());
+    /// ```
+    pub fn weld_exact(self) -> String
+    where
+        G: AsRef<str>,
+        E: AsRef<str>,
+    {
+        let glue = self.glue.as_ref();
+        let pieces: Vec<_> = self.pieces.into_iter().map(|piece| match piece {
+            Piece::Glue => None,
+            Piece::Elem(f) => Some(f()),
+        }).collect();
+
+        let len = pieces.iter().map(|piece| match *piece {
+            None => glue.len(),
+            Some(ref elem) => elem.as_ref().len(),
+        }).sum();
+
+        let mut welded = String::with_capacity(len);
+        for piece in &pieces {
+            match *piece {
+                None => welded.push_str(glue),
+                Some(ref elem) => welded.push_str(elem.as_ref()),
+            }
+        }
+        welded
+    }
+
+    /// Evaluate every element producer, in order, and write the results and the glues
+    /// to the writer without accumulating them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(' ', || 12).elem(|| 14);
+    ///
+    /// let mut bytes = Vec::new();
+    /// welder.weld_to_writer(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(b"12 14", bytes.as_slice());
+    /// ```
+    pub fn weld_to_writer<W: Write>(self, mut writer: W) -> io::Result<()>
+    where
+        G: fmt::Display,
+        E: fmt::Display,
+    {
+        for piece in self.pieces {
+            match piece {
+                Piece::Glue => write!(writer, "{}", self.glue)?,
+                Piece::Elem(f) => write!(writer, "{}", f())?,
+            }
+        }
+        writer.flush()
+    }
+
+    /// Evaluate every element producer, in order, and write the results and the glues
+    /// to the standard output, it is locked once for the whole output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(' ', || 42).elem(|| 1337);
+    ///
+    /// welder.weld_to_stdout().unwrap();
+    /// ```
+    pub fn weld_to_stdout(self) -> io::Result<()>
+    where
+        G: fmt::Display,
+        E: fmt::Display,
+    {
+        let stdout = io::stdout();
+        let lock = stdout.lock();
+        self.weld_to_writer(BufWriter::new(lock))
+    }
+
+    /// Evaluate every element producer, in order, and write the results and the glues
+    /// to the standard error, it is locked once for the whole output.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(": ", || "warning").elem(|| "disk almost full\n");
+    ///
+    /// welder.weld_to_stderr().unwrap();
+    /// ```
+    pub fn weld_to_stderr(self) -> io::Result<()>
+    where
+        G: fmt::Display,
+        E: fmt::Display,
+    {
+        let stderr = io::stderr();
+        let lock = stderr.lock();
+        self.weld_to_writer(BufWriter::new(lock))
+    }
+
+    /// Evaluate every element producer, in order, and accumulate the results
+    /// into several outputs of at most `n` elements each.
+    ///
+    /// The elements of a shard are glued together, no glue is kept between shards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(0, || 12).elem(|| 14).elem(|| 16);
+    ///
+    /// let shards: Vec<Vec<_>> = welder.weld_shards(2);
+    /// assert_eq!(vec![vec![12, 0, 14], vec![16]], shards);
+    /// ```
+    pub fn weld_shards<T>(mut self, n: usize) -> Vec<T>
+    where
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        assert!(n != 0, "shards must contain at least one element");
+
+        let mut shards = Vec::new();
+        let mut welded = T::default();
+        let mut count = 0;
+        for piece in self.pieces {
+            if let Piece::Elem(f) = piece {
+                if count == n {
+                    shards.push(mem::take(&mut welded));
+                    count = 0;
+                }
+                if count != 0 {
+                    self.glue.apply(&mut welded);
+                }
+                welded.weld_elem(f());
+                count += 1;
+            }
+        }
+        if count != 0 {
+            shards.push(welded);
+        }
+        shards
+    }
+
+    /// This function will add the element producer without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(' ', || "foo");
+    ///
+    /// let welder = welder.elem_no_glue(|| "bar");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("foobar", &string);
+    /// ```
+    pub fn elem_no_glue<F>(mut self, elem: F) -> Self
+    where
+        F: FnOnce() -> E + 'a,
+    {
+        self.pieces.push(Piece::Elem(Box::new(elem)));
+        self
+    }
+
+    /// Push a new element producer to the already accumulated ones.
+    /// This function will add a glue element in front of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::new(' ');
+    ///
+    /// let welder = welder.elem(|| "foo").elem(|| "bar");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!(" foo bar", &string);
+    /// ```
+    pub fn elem<F>(self, elem: F) -> Self
+    where
+        F: FnOnce() -> E + 'a,
+    {
+        self.elem_glue_left(elem)
+    }
+
+    /// Push all element producers to the already accumulated ones.
+    /// This function will add a glue in front of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::new(' ');
+    ///
+    /// let welder = welder.elems(vec![|| "foo", || "bar"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!(" foo bar", &string);
+    /// ```
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: FnOnce() -> E + 'a,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+
+    /// It will add a glue only to right of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::with_start(' ', || "foo");
+    ///
+    /// let welder = welder.elem_glue_right(|| "bar");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("foobar ", &string);
+    /// ```
+    pub fn elem_glue_right<F>(mut self, elem: F) -> Self
+    where
+        F: FnOnce() -> E + 'a,
+    {
+        self.pieces.push(Piece::Elem(Box::new(elem)));
+        self.pieces.push(Piece::Glue);
+        self
+    }
+
+    /// This is the default elem function.
+    /// It will add a glue only to the left of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::new(' ');
+    ///
+    /// let welder = welder.elem_glue_left(|| "foo");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!(" foo", &string);
+    /// ```
+    pub fn elem_glue_left<F>(mut self, elem: F) -> Self
+    where
+        F: FnOnce() -> E + 'a,
+    {
+        self.pieces.push(Piece::Glue);
+        self.pieces.push(Piece::Elem(Box::new(elem)));
+        self
+    }
+
+    /// This function will add a glue on both sides of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::LazyWelder;
+    ///
+    /// let welder = LazyWelder::new(' ');
+    ///
+    /// let welder = welder.elem_glue_both(|| "foo");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!(" foo ", &string);
+    /// ```
+    pub fn elem_glue_both<F>(mut self, elem: F) -> Self
+    where
+        F: FnOnce() -> E + 'a,
+    {
+        self.pieces.push(Piece::Glue);
+        self.pieces.push(Piece::Elem(Box::new(elem)));
+        self.pieces.push(Piece::Glue);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use super::LazyWelder;
+
+    #[test]
+    fn lazy_welder_not_evaluated_when_dropped() {
+        let calls = Cell::new(0);
+
+        let welder = LazyWelder::with_start(' ', || { calls.set(calls.get() + 1); "foo" })
+                                .elem(|| { calls.set(calls.get() + 1); "bar" });
+        drop(welder);
+
+        assert_eq!(0, calls.get());
+    }
+
+    #[test]
+    fn lazy_welder_evaluated_in_order() {
+        let calls = Cell::new(0);
+        let next = || { calls.set(calls.get() + 1); calls.get() };
+
+        let vec: Vec<_> = LazyWelder::with_start(0, &next)
+                                .elem(&next)
+                                .elem(&next)
+                                .weld();
+
+        assert_eq!(&[1, 0, 2, 0, 3], vec.as_slice());
+    }
+
+    #[test]
+    fn lazy_welder_weld_exact_evaluates_once() {
+        let calls = Cell::new(0);
+        let next = || { calls.set(calls.get() + 1); calls.get().to_string() };
+
+        let string = LazyWelder::with_start(String::from("--"), &next)
+                                .elem(&next)
+                                .elem_glue_both(&next)
+                                .weld_exact();
+
+        assert_eq!("1--2--3--", &string);
+        assert_eq!(3, calls.get());
+    }
+}
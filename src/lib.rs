@@ -29,128 +29,1101 @@
 //! assert_eq!(&[12, 0, 14, 0, 16, 0, 18], vec.as_slice());
 //! ```
 
-use std::iter::once;
+#[cfg(feature = "arbitrary")]
+extern crate arbitrary;
+#[cfg(feature = "memmap2")]
+extern crate memmap2;
+#[cfg(feature = "proptest")]
+extern crate proptest;
+#[cfg(feature = "tempfile")]
+extern crate tempfile;
+#[cfg(feature = "derive")]
+extern crate welder_derive;
+
+#[macro_use]
+mod macros;
+
+mod borrowed;
+mod budget;
+mod capacity;
+mod dedup;
+mod empty;
+mod expand;
+mod file;
+mod format;
+#[cfg(feature = "arbitrary")]
+pub mod fuzz;
+mod glue;
+mod iter;
+mod last;
+mod lazy;
+mod limit;
+mod map;
+mod memo;
+mod merge;
+mod observe;
+mod ops;
+mod pipeline;
+#[cfg(feature = "memmap2")]
+mod mmap;
+pub mod presets;
+mod push;
+mod sanitize;
+mod segment;
+mod slice;
+#[cfg(feature = "tempfile")]
+mod spill;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+mod surround;
+mod transaction;
+mod unweld;
+mod validate;
+mod weldable;
+mod write;
+
+pub use borrowed::BorrowedWelder;
+pub use budget::Budgeted;
+pub use capacity::{Capacity, Truncate};
+pub use dedup::Deduped;
+pub use empty::IsEmpty;
+pub use expand::Expanded;
+pub use glue::{CloneGlue, CycleGlue, FirstGlue, Glue, GlueIter, IndexedGlue};
+pub use iter::WeldIterator;
+pub use last::FinalGlued;
+pub use lazy::LazyWelder;
+pub use limit::{Limited, LimitExceeded};
+pub use map::MappedElems;
+pub use memo::{Elems, IntoElems, MemoWelder, WeldDisplay};
+#[cfg(feature = "memmap2")]
+pub use mmap::MmapBuffer;
+pub use observe::Observed;
+pub use pipeline::Pipeline;
+pub use sanitize::{Sanitized, ControlChars};
+pub use segment::SegmentedWelder;
+pub use slice::WeldSlice;
+#[cfg(feature = "tempfile")]
+pub use spill::{SpillBuffer, Spilled, DEFAULT_SPILL_THRESHOLD};
+pub use surround::Surrounded;
+pub use transaction::Transaction;
+pub use unweld::Unwelder;
+pub use validate::Validated;
+pub use weldable::{Extended, Weldable};
+#[cfg(feature = "derive")]
+pub use welder_derive::Weld;
+
+use std::borrow::Cow;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, RangeFrom};
+
+/// A saved state of a `Welder` that it can be restored to later on,
+/// the state of its glue included.
+///
+/// A snapshot is only meaningful for the `Welder` it was taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Snapshot<G> {
+    glue: G,
+    len: usize,
+    elems: usize,
+    glues: usize,
+}
 
 /// An helper struct to accumalate elements.
+///
+/// Two `Welder`s are equal, and hash the same, if both their glues
+/// and their accumulated values are equal.
+#[derive(Clone, Default)]
 pub struct Welder<G, T> {
     glue: G,
     welded: T,
+    delta_start: usize,
+    elems: usize,
+    glues: usize,
+    chunk_open: bool,
+}
+
+/// A `Welder` that accumulates into a `String`.
+pub type StringWelder<G> = Welder<G, String>;
+
+/// A `Welder` that accumulates into a `Vec`, glued with values of the same type.
+pub type VecWelder<T> = Welder<T, Vec<T>>;
+
+impl<G: fmt::Debug, T: fmt::Debug> fmt::Debug for Welder<G, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Welder")
+            .field("glue", &self.glue)
+            .field("welded", &self.welded)
+            .finish()
+    }
+}
+
+impl<G: PartialEq, T: PartialEq> PartialEq for Welder<G, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.glue == other.glue && self.welded == other.welded
+    }
+}
+
+impl<G: Eq, T: Eq> Eq for Welder<G, T> {}
+
+impl<G: Hash, T: Hash> Hash for Welder<G, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.glue.hash(state);
+        self.welded.hash(state);
+    }
+}
+
+impl<G, T: Default> Welder<G, T> {
+    /// Create an empty `Welder` just by defining the glue used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::new(' ');
+    ///
+    /// let string: String = welder.weld();
+    ///
+    /// assert_eq!("", &string);
+    /// ```
+    pub fn new(glue: G) -> Self {
+        Welder {
+            glue,
+            welded: <T as Default>::default(),
+            delta_start: 0,
+            elems: 0,
+            glues: 0,
+            chunk_open: false,
+        }
+    }
+
+    /// Create a `Welder` with a first value and the glue it will use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(' ', "foo");
+    ///
+    /// let string: String = welder.weld();
+    ///
+    /// assert_eq!("foo", &string);
+    /// ```
+    pub fn with_start<E>(glue: G, start: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        let welder = Welder::new(glue);
+        welder.elem_no_glue(start)
+    }
+
+    /// Weld the entries of the map sorted by key, the key and the value are separated
+    /// by the `kv_glue` and the entries by the glue, see `Welder::elems_sorted_pairs`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use welder::Welder;
+    ///
+    /// let mut params = HashMap::new();
+    /// params.insert("sort", "asc");
+    /// params.insert("page", "2");
+    ///
+    /// let string: String = Welder::weld_map('&', '=', &params);
+    ///
+    /// assert_eq!("page=2&sort=asc", &string);
+    /// ```
+    pub fn weld_map<M, K, V, H>(glue: G, kv_glue: H, map: M) -> T
+    where
+        G: Glue<T>,
+        M: IntoIterator<Item = (K, V)>,
+        K: Ord,
+        H: Glue<T>,
+        T: Weldable<K> + Weldable<V>,
+    {
+        let nothing = |_: &mut T| ();
+        Welder::with_first_glue(nothing, glue).elems_sorted_pairs(map, kv_glue).weld()
+    }
+
+    /// Create a `Welder` from all the items of the iterator,
+    /// the first item is the start and the following ones are glued to it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let names = vec!["Alice", "Bob", "Carol"];
+    ///
+    /// let string: String = Welder::from_iter(", ", names).weld();
+    ///
+    /// assert_eq!("Alice, Bob, Carol", &string);
+    /// ```
+    pub fn from_iter<I>(glue: G, iter: I) -> Self
+    where
+        G: Glue<T>,
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        let mut iter = iter.into_iter();
+        let welder = Welder::new(glue);
+        match iter.next() {
+            Some(start) => welder.elem_no_glue(start).elems(iter),
+            None => welder,
+        }
+    }
+}
+
+/// Shortcuts for the most common glues, the glue is put in front of every
+/// element added with `elem` like with `Welder::new`. The functions of the
+/// `presets` module with the same names only put it between the elements.
+impl StringWelder<char> {
+    /// Create an empty `Welder` that glues the elements with a comma.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_comma().elem_no_glue("1").elems(vec!["2", "3"]);
+    ///
+    /// assert_eq!("1,2,3", &welder.weld());
+    /// ```
+    pub fn with_comma() -> Self {
+        Welder::new(',')
+    }
+
+    /// Create an empty `Welder` that glues the elements with a space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_space().elem_no_glue("ls").elem("-l");
+    ///
+    /// assert_eq!("ls -l", &welder.weld());
+    /// ```
+    pub fn with_space() -> Self {
+        Welder::new(' ')
+    }
+
+    /// Create an empty `Welder` that glues the elements with a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_newline().elems_glue_right(vec!["first", "second"]);
+    ///
+    /// assert_eq!("first\nsecond\n", &welder.weld());
+    /// ```
+    pub fn with_newline() -> Self {
+        Welder::new('\n')
+    }
+
+    /// Create an empty `Welder` that glues the elements with a tab.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_tab().elem_no_glue("name").elem("age");
+    ///
+    /// assert_eq!("name\tage", &welder.weld());
+    /// ```
+    pub fn with_tab() -> Self {
+        Welder::new('\t')
+    }
+}
+
+impl<'a, G, T> Welder<G, &'a mut T> {
+    /// Create an empty `Welder` that accumulates its values at the end
+    /// of a target owned by the caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut buffer = Vec::new();
+    ///
+    /// for line in 0..2 {
+    ///     Welder::on(&mut buffer, 0).elem_glue_right(line + 1).elem_glue_right(9);
+    /// }
+    ///
+    /// assert_eq!(vec![1, 0, 9, 0, 2, 0, 9, 0], buffer);
+    /// ```
+    pub fn on(target: &'a mut T, glue: G) -> Self {
+        Welder {
+            glue,
+            welded: target,
+            delta_start: 0,
+            elems: 0,
+            glues: 0,
+            chunk_open: false,
+        }
+    }
+}
+
+impl<I, T> Welder<GlueIter<I>, T>
+where
+    I: Iterator + Clone,
+    T: Default,
+{
+    /// Create an empty `Welder` whose glue is made of every item of the iterator,
+    /// they are all pushed again each time a glue is needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_glue_iter(&[0u8, 0]).elem_no_glue(1).elem(2);
+    ///
+    /// let bytes: Vec<u8> = welder.weld();
+    /// assert_eq!(vec![1, 0, 0, 2], bytes);
+    ///
+    /// let welder = Welder::with_glue_iter("--".chars()).elem_no_glue('a').elem('b');
+    ///
+    /// let chars: Vec<char> = welder.weld();
+    /// assert_eq!(vec!['a', '-', '-', 'b'], chars);
+    /// ```
+    pub fn with_glue_iter<J>(glue: J) -> Self
+    where
+        J: IntoIterator<IntoIter = I, Item = I::Item>,
+    {
+        Welder::new(GlueIter::new(glue))
+    }
+}
+
+impl<F, T: Default> Welder<IndexedGlue<F>, T> {
+    /// Create an empty `Welder` whose glue is computed by the closure
+    /// from the number of glues already applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_indexed_glue(|i: usize| format!(" {}. ", i + 1));
+    ///
+    /// let welder = welder.elem_no_glue("steps:").elem("open").elem("close");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("steps: 1. open 2. close", &string);
+    /// ```
+    pub fn with_indexed_glue(f: F) -> Self {
+        Welder::new(IndexedGlue::new(f))
+    }
+}
+
+impl<G, T: Default> Welder<CycleGlue<G>, T> {
+    /// Create an empty `Welder` that cycles through the glues, one after the other.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_glues(vec!["=", "&"]);
+    ///
+    /// let welder = welder.elem_no_glue("key").elems(vec!["value", "other", "thing"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("key=value&other=thing", &string);
+    /// ```
+    pub fn with_glues<I>(glues: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Welder::new(CycleGlue::new(glues))
+    }
 }
 
-impl<G, T: Default> Welder<G, T> {
-    /// Create an empty `Welder` just by defining the glue used.
+impl<F, G, T: Default> Welder<FirstGlue<F, G>, T> {
+    /// Create an empty `Welder` that uses the `first` glue in front of
+    /// the first glued element and the `rest` glue for the following ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_first_glue('?', '&');
+    ///
+    /// let welder = welder.elem_no_glue("/search").elems(vec!["a=1", "b=2", "c=3"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("/search?a=1&b=2&c=3", &string);
+    /// ```
+    pub fn with_first_glue(first: F, rest: G) -> Self {
+        Welder::new(FirstGlue::new(first, rest))
+    }
+}
+
+impl<G, T> Welder<G, T> {
+    /// Count the elements just added, they end the chunk
+    /// that `io::Write` may have left open.
+    fn count_elems(&mut self, elems: usize) {
+        self.elems += elems;
+        self.chunk_open = false;
+    }
+
+    /// Retrieve the accumulated values from the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(' ', "foo");
+    ///
+    /// let welder = welder.elem("bar").elem("baz").elem("foo");
+    ///
+    /// let string: String = welder.weld();
+    ///
+    /// assert_eq!("foo bar baz foo", &string);
+    /// ```
+    pub fn weld(self) -> T {
+        self.welded
+    }
+
+    /// Retrieve the accumulated values followed by the terminator,
+    /// it is appended even if nothing has been accumulated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(";\n", "let a = 1").elem("let b = 2");
+    ///
+    /// let string: String = welder.weld_with_terminator(";\n");
+    ///
+    /// assert_eq!("let a = 1;\nlet b = 2;\n", &string);
+    /// ```
+    pub fn weld_with_terminator<H>(mut self, mut terminator: H) -> T
+    where
+        H: Glue<T>,
+    {
+        terminator.apply(&mut self.welded);
+        self.welded
+    }
+
+    /// The number of elements added to this `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "a").elems(vec!["b", "c"]);
+    ///
+    /// assert_eq!(3, welder.len());
+    /// assert_eq!(2, welder.glue_count());
+    /// ```
+    pub fn len(&self) -> usize {
+        self.elems
+    }
+
+    /// Returns `true` if no element has been added to this `Welder`,
+    /// glues are not taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(", ");
+    /// assert!(welder.is_empty());
+    ///
+    /// let welder = welder.elem("errors:");
+    /// assert!(!welder.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.elems == 0
+    }
+
+    /// The number of glues added to this `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::new(0).elem_glue_both(1).elem_no_glue(2);
+    ///
+    /// assert_eq!(2, welder.glue_count());
+    /// ```
+    pub fn glue_count(&self) -> usize {
+        self.glues
+    }
+
+    /// Look at the values accumulated so far without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 12).elem(14);
+    ///
+    /// assert_eq!(3, welder.peek().len());
+    /// ```
+    pub fn peek(&self) -> &T {
+        &self.welded
+    }
+
+    /// Create a `Welder` from a glue and already accumulated values,
+    /// the next elements are accumulated at the end of them.
+    ///
+    /// The values given are counted as a single element, if there are any,
+    /// and no glue, what they were made of is not known anymore.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::from_parts(", ", String::from("foo, bar"));
+    /// assert_eq!(1, welder.len());
+    ///
+    /// let welder = welder.elem("baz");
+    ///
+    /// assert_eq!(2, welder.len());
+    /// assert_eq!("foo, bar, baz", &welder.weld());
+    /// ```
+    pub fn from_parts(glue: G, welded: T) -> Self
+    where
+        T: Capacity,
+    {
+        let elems = if welded.is_empty() { 0 } else { 1 };
+        Welder {
+            glue,
+            welded,
+            delta_start: 0,
+            elems,
+            glues: 0,
+            chunk_open: false,
+        }
+    }
+
+    /// Retrieve the glue and the accumulated values,
+    /// `Welder::from_parts` can be used to continue welding later.
+    ///
+    /// The counts of elements and glues are not part of the parts,
+    /// see `Welder::from_parts`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "foo");
+    /// let (glue, welded) = welder.into_parts();
+    ///
+    /// let welder = Welder::from_parts(glue, welded).elem("bar");
+    ///
+    /// assert_eq!("foo, bar", &welder.weld());
+    /// ```
+    pub fn into_parts(self) -> (G, T) {
+        (self.glue, self.welded)
+    }
+
+    /// Retrieve the accumulated values converted into another type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "foo").elem("bar");
+    ///
+    /// let boxed = welder.weld_to::<Box<str>>();
+    ///
+    /// assert_eq!("foo, bar", &*boxed);
+    /// ```
+    pub fn weld_to<U>(self) -> U
+    where
+        U: From<T>,
+    {
+        U::from(self.welded)
+    }
+
+    /// Append the accumulated values at the end of an existing target.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut buffer = String::from("log: ");
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "foo").elem("bar");
+    /// welder.weld_into(&mut buffer);
+    ///
+    /// assert_eq!("log: foo, bar", &buffer);
+    /// ```
+    pub fn weld_into(self, target: &mut T)
+    where
+        T: Weldable<T>,
+    {
+        target.weld_elem(self.welded)
+    }
+
+    /// Convert the accumulated values into another container,
+    /// the next elements and glues are accumulated into the new one.
+    ///
+    /// The next call to `weld_delta` returns the whole content of the new container.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    ///
+    /// let welder = welder.map_target(|s| s.chars().collect::<Vec<_>>()).elem('!');
+    ///
+    /// assert_eq!(&['f', 'o', 'o', ' ', '!'], welder.weld().as_slice());
+    /// ```
+    pub fn map_target<U, F>(self, f: F) -> Welder<G, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        Welder {
+            glue: self.glue,
+            welded: f(self.welded),
+            delta_start: 0,
+            elems: self.elems,
+            glues: self.glues,
+            chunk_open: self.chunk_open,
+        }
+    }
+
+    /// Replace the glue used for the next elements,
+    /// the already accumulated values are kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(", ", "SELECT a").elem("b");
+    ///
+    /// let welder = welder.set_glue(" ").elem("FROM t WHERE x = 1");
+    /// let welder = welder.set_glue(" AND ").elem("y = 2");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("SELECT a, b FROM t WHERE x = 1 AND y = 2", &string);
+    /// ```
+    pub fn set_glue(self, glue: G) -> Self {
+        Welder { glue, ..self }
+    }
+
+    /// Replace the glue used for the next elements by one of another type,
+    /// the already accumulated values are kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(", ", "Host: a").elem("Accept: b");
+    ///
+    /// let welder = welder.with_glue('\n').elem("body line 1").elem("body line 2");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("Host: a, Accept: b\nbody line 1\nbody line 2", &string);
+    /// ```
+    pub fn with_glue<H>(self, glue: H) -> Welder<H, T> {
+        self.map_glue(|_| glue)
+    }
+
+    /// Change the glue used for the next elements, it can be of another type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start('-', "a").elem("b");
+    ///
+    /// let welder = welder.map_glue(|c| format!(" {} ", c)).elem("c");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("a-b - c", &string);
+    /// ```
+    pub fn map_glue<H, F>(self, f: F) -> Welder<H, T>
+    where
+        F: FnOnce(G) -> H,
+    {
+        Welder {
+            glue: f(self.glue),
+            welded: self.welded,
+            delta_start: self.delta_start,
+            elems: self.elems,
+            glues: self.glues,
+            chunk_open: self.chunk_open,
+        }
+    }
+
+    /// Run the closure with a `Welder` that uses another glue,
+    /// the original glue is used again for the elements pushed afterwards.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(", ", "a").elem("b");
+    ///
+    /// let welder = welder.glue_scope('|', |w| {
+    ///     w.elem_no_glue(", [").elem_no_glue("x").elem("y").elem("z").elem_no_glue("]")
+    /// });
+    ///
+    /// let string: String = welder.elem("c").weld();
+    /// assert_eq!("a, b, [x|y|z], c", &string);
+    /// ```
+    pub fn glue_scope<H, F>(self, glue: H, f: F) -> Self
+    where
+        F: FnOnce(Welder<H, T>) -> Welder<H, T>,
+    {
+        let Welder { glue: original, welded, delta_start, elems, glues, chunk_open } = self;
+        let scoped = f(Welder { glue, welded, delta_start, elems, glues, chunk_open });
+        scoped.map_glue(|_| original)
+    }
+
+    /// This function will add the element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(' ', "foo");
+    ///
+    /// let welder = welder.elem_no_glue("bar");
+    /// let welder = welder.elem_no_glue("baz");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("foobarbaz", &string);
+    /// ```
+    pub fn elem_no_glue<E>(mut self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        self.push_elem_no_glue(elem);
+        self
+    }
+
+    /// This function will add each element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::with_start(' ', "foo");
+    ///
+    /// let welder = welder.elems_no_glue(vec!["bar", "baz"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("foobarbaz", &string);
+    /// ```
+    pub fn elems_no_glue<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        self.push_elems_no_glue(elems);
+        self
+    }
+
+    /// This function will add the element with the given glue in front of it,
+    /// instead of the glue of the `Welder`, for this element only.
     ///
     /// # Examples
     ///
     /// ```
     /// use welder::Welder;
     ///
-    /// let welder = Welder::new(' ');
+    /// let welder = Welder::with_start(", ", "fast").elem("cheap");
     ///
-    /// let string: String = welder.weld();
+    /// let welder = welder.elem_with_glue(" but ", "not reliable");
     ///
-    /// assert_eq!("", &string);
+    /// let string: String = welder.weld();
+    /// assert_eq!("fast, cheap but not reliable", &string);
     /// ```
-    pub fn new(glue: G) -> Self {
-        Welder {
-            glue: glue,
-            welded: <T as Default>::default(),
-        }
+    pub fn elem_with_glue<H, E>(mut self, glue: H, elem: E) -> Self
+    where
+        H: Glue<T>,
+        T: Weldable<E>,
+    {
+        self.push_elem_with_glue(glue, elem);
+        self
     }
 
-    /// Create a `Welder` with a first value and the glue it will use.
+    /// This function will add each element with the given glue in front of it,
+    /// instead of the glue of the `Welder`, for these elements only.
     ///
     /// # Examples
     ///
     /// ```
     /// use welder::Welder;
     ///
-    /// let welder = Welder::with_start(' ', "foo");
+    /// let welder = Welder::with_start(", ", "name");
     ///
-    /// let string: String = welder.weld();
+    /// let welder = welder.elems_with_glue(": ", vec!["John"]).elem("age").elem_with_glue(": ", "42");
     ///
-    /// assert_eq!("foo", &string);
+    /// let string: String = welder.weld();
+    /// assert_eq!("name: John, age: 42", &string);
     /// ```
-    pub fn with_start<E>(glue: G, start: E) -> Self
+    pub fn elems_with_glue<H, I>(mut self, glue: H, elems: I) -> Self
     where
-        T: Extend<E>
+        H: Glue<T>,
+        I: IntoIterator,
+        T: Weldable<I::Item>,
     {
-        let welder = Welder::new(glue);
-        welder.elem_no_glue(start)
+        self.push_elems_with_glue(glue, elems);
+        self
     }
 }
 
-impl<G, T> Welder<G, T> {
-    /// Retrieve the accumulated values from the `Welder`.
+impl<G> Welder<G, String> {
+    /// Look at the `String` accumulated so far without consuming the `Welder`.
     ///
     /// # Examples
     ///
     /// ```
     /// use welder::Welder;
     ///
-    /// let welder = Welder::with_start(' ', "foo");
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "foo");
     ///
-    /// let welder = welder.elem("bar").elem("baz").elem("foo");
+    /// let welder = if welder.as_str().len() < 8 { welder.elem("bar") } else { welder };
     ///
-    /// let string: String = welder.weld();
+    /// assert_eq!("foo, bar", welder.as_str());
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.welded
+    }
+}
+
+impl<G, E> Welder<G, Vec<E>> {
+    /// Look at the items accumulated so far without consuming the `Welder`.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!("foo bar baz foo", &string);
     /// ```
-    pub fn weld(self) -> T {
-        self.welded
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 12).elem(14);
+    ///
+    /// assert_eq!(&[12, 0, 14], welder.as_slice());
+    /// ```
+    pub fn as_slice(&self) -> &[E] {
+        &self.welded
     }
+}
 
-    /// This function will add the element without any glue.
+impl<G, T: Truncate> Welder<G, T> {
+    /// Retrieve only what has been accumulated since the last call to this function,
+    /// glues included, without consuming the `Welder`.
     ///
     /// # Examples
     ///
     /// ```
     /// use welder::Welder;
     ///
-    /// let welder = Welder::with_start(' ', "foo");
+    /// let mut welder: Welder<_, String> = Welder::with_start(' ', "foo").elem("bar");
+    /// assert_eq!("foo bar", welder.weld_delta());
     ///
-    /// let welder = welder.elem_no_glue("bar");
-    /// let welder = welder.elem_no_glue("baz");
+    /// let mut welder = welder.elem("baz");
+    /// assert_eq!(" baz", welder.weld_delta());
+    /// assert_eq!("", welder.weld_delta());
     ///
-    /// let string: String = welder.weld();
-    /// assert_eq!("foobarbaz", &string);
+    /// assert_eq!("foo bar baz", &welder.weld());
     /// ```
-    pub fn elem_no_glue<E>(mut self, elem: E) -> Self
+    pub fn weld_delta(&mut self) -> &T::Output
     where
-        T: Extend<E>
+        T: Index<RangeFrom<usize>>,
     {
-        self.welded.extend(once(elem));
-        self
+        let start = self.delta_start;
+        self.delta_start = self.welded.len();
+        &self.welded[start..]
     }
 
-    /// This function will add each element without any glue.
+    /// Save the current state of the `Welder` without cloning the accumulated values,
+    /// only the glue is cloned so that a stateful glue is restored too.
     ///
     /// # Examples
     ///
     /// ```
     /// use welder::Welder;
     ///
-    /// let welder = Welder::with_start(' ', "foo");
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
     ///
-    /// let welder = welder.elems_no_glue(vec!["bar", "baz"]);
+    /// let snapshot = welder.snapshot();
+    /// let welder = welder.elem("bar").elem("baz");
     ///
-    /// let string: String = welder.weld();
-    /// assert_eq!("foobarbaz", &string);
+    /// let welder = welder.restore(snapshot);
+    /// assert_eq!("foo", &welder.weld());
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 12);
+    ///
+    /// let snapshot = welder.snapshot();
+    /// let welder = welder.elem(14).restore(snapshot).elem(16);
+    ///
+    /// assert_eq!(&[12, 0, 16], welder.weld().as_slice());
     /// ```
-    pub fn elems_no_glue<I>(mut self, elems: I) -> Self
+    pub fn snapshot(&self) -> Snapshot<G>
     where
-        I: IntoIterator,
-        T: Extend<I::Item>,
+        G: Clone,
     {
-        self.welded.extend(elems);
+        Snapshot {
+            glue: self.glue.clone(),
+            len: self.welded.len(),
+            elems: self.elems,
+            glues: self.glues,
+        }
+    }
+
+    /// Discard every element and glue added since the snapshot was taken,
+    /// the glue is put back in the state it was in.
+    ///
+    /// Restoring a snapshot that is ahead of the current state has no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    ///
+    /// let welder = welder.elem("bar");
+    /// let snapshot = welder.snapshot();
+    ///
+    /// let welder = welder.elem("baz").restore(snapshot).elem("boat");
+    /// assert_eq!("foo bar boat", &welder.weld());
+    ///
+    /// let welder: Welder<_, String> = Welder::with_first_glue('?', '&').elem_no_glue("/s");
+    ///
+    /// let snapshot = welder.snapshot();
+    /// let welder = welder.elem("a=1").restore(snapshot).elem("b=2");
+    ///
+    /// assert_eq!("/s?b=2", &welder.weld());
+    /// ```
+    pub fn restore(mut self, snapshot: Snapshot<G>) -> Self {
+        self.rollback(snapshot);
         self
     }
+
+    /// Take a checkpoint to roll back to, to try a branch of insertions
+    /// and discard it on failure, without cloning the accumulated values.
+    ///
+    /// The checkpoint is a `Snapshot`, it is given back to `rollback`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(", ", "a");
+    ///
+    /// for branch in &[vec!["b", "oops"], vec!["c", "d"]] {
+    ///     let checkpoint = welder.checkpoint();
+    ///     for elem in branch {
+    ///         welder.push_elem(elem);
+    ///     }
+    ///     if branch.contains(&"oops") {
+    ///         welder.rollback(checkpoint);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!("a, c, d", &welder.weld());
+    /// ```
+    pub fn checkpoint(&self) -> Snapshot<G>
+    where
+        G: Clone,
+    {
+        self.snapshot()
+    }
+
+    /// Discard every element and glue added since the checkpoint,
+    /// or the snapshot, was taken, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(", ", "a");
+    ///
+    /// let checkpoint = welder.checkpoint();
+    /// welder.push_elem("speculative");
+    /// welder.rollback(checkpoint);
+    ///
+    /// assert_eq!("a", &welder.weld());
+    /// ```
+    pub fn rollback(&mut self, snapshot: Snapshot<G>) {
+        if snapshot.len > self.welded.len() {
+            return
+        }
+        self.glue = snapshot.glue;
+        self.welded.truncate(snapshot.len);
+        self.delta_start = self.delta_start.min(self.welded.len());
+        self.elems = self.elems.min(snapshot.elems);
+        self.glues = self.glues.min(snapshot.glues);
+        self.chunk_open = false;
+    }
+}
+
+impl<G, H, T> Welder<G, Vec<Welder<H, T>>> {
+    /// Weld each inner `Welder` and join their outputs with the glue of this one,
+    /// the inner welders are expected to be added without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let first: Welder<_, String> = Welder::with_start(' ', "Once").elem("upon").elem("a time.");
+    /// let second: Welder<_, String> = Welder::with_start(' ', "The").elem("end.");
+    ///
+    /// let document: Welder<_, Vec<_>> = Welder::new("\n\n").elems_no_glue(vec![first, second]);
+    ///
+    /// let string: String = document.weld_flatten();
+    /// assert_eq!("Once upon a time.\n\nThe end.", &string);
+    /// ```
+    pub fn weld_flatten(mut self) -> T
+    where
+        G: Glue<T>,
+        T: Default + Weldable<T>,
+    {
+        let mut welded = T::default();
+        for (i, welder) in self.welded.into_iter().enumerate() {
+            if i != 0 {
+                self.glue.apply(&mut welded);
+            }
+            welded.weld_elem(welder.weld());
+        }
+        welded
+    }
 }
 
 impl<G, T> Welder<G, T>
 where
-    G: Clone,
-    T: Extend<G>
+    G: Glue<T>,
 {
     /// Push a new value to the already accumulated values.
     /// This function will add a glue element in front of the element.
@@ -171,7 +1144,7 @@ where
     /// ```
     pub fn elem<E>(self, elem: E) -> Self
     where
-        T: Extend<E>
+        T: Weldable<E>
     {
         self.elem_glue_left(elem)
     }
@@ -194,11 +1167,165 @@ where
     pub fn elems<I>(self, elems: I) -> Self
     where
         I: IntoIterator,
-        T: Extend<I::Item>,
+        T: Weldable<I::Item>,
     {
         self.elems_glue_left(elems)
     }
 
+    /// Push the element with a glue in front of it only if the condition is `true`,
+    /// nothing is added otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let verbose = false;
+    /// let welder = Welder::with_start(' ', "ls").elem_if(true, "-l").elem_if(verbose, "-v");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("ls -l", &string);
+    /// ```
+    pub fn elem_if<E>(self, cond: bool, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        if cond { self.elem(elem) } else { self }
+    }
+
+    /// Push the element with a glue in front of it only if there is one,
+    /// nothing is added for `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let age = Some("42");
+    /// let welder = Welder::with_start(", ", "John").elem_opt(None::<&str>).elem_opt(age);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("John, 42", &string);
+    /// ```
+    pub fn elem_opt<E>(self, elem: Option<E>) -> Self
+    where
+        T: Weldable<E>
+    {
+        match elem {
+            Some(elem) => self.elem(elem),
+            None => self,
+        }
+    }
+
+    /// Push the element with a glue in front of it if there is one,
+    /// the default element is pushed for `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let email = None;
+    /// let welder = Welder::with_start(", ", "John").elem_or(email, "<none>");
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("John, <none>", &string);
+    /// ```
+    pub fn elem_or<E>(self, elem: Option<E>, default: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        self.elem(elem.unwrap_or(default))
+    }
+
+    /// Push the element with a glue in front of it if there is one,
+    /// the element returned by the closure is pushed for `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let id = Some(12);
+    /// let welder = Welder::with_start(0, 1).elem_or_else(id, || 42).elem_or_else(None, || 42);
+    ///
+    /// let vec: Vec<_> = welder.weld();
+    /// assert_eq!(&[1, 0, 12, 0, 42], vec.as_slice());
+    /// ```
+    pub fn elem_or_else<E, F>(self, elem: Option<E>, default: F) -> Self
+    where
+        F: FnOnce() -> E,
+        T: Weldable<E>
+    {
+        self.elem(elem.unwrap_or_else(default))
+    }
+
+    /// Push each key and value separated by the `kv_glue`, with a glue
+    /// in front of each pair like `elems`, a pair counts as one element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_first_glue('?', '&').elem_no_glue("/search");
+    /// let welder = welder.elems_pairs(vec![("page", "2"), ("sort", "asc")], '=');
+    ///
+    /// assert_eq!(3, welder.len());
+    /// assert_eq!("/search?page=2&sort=asc", &welder.weld());
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start("\r\n", "HTTP/1.1 200 OK");
+    /// let welder = welder.elems_pairs(vec![("Content-Length", "0")], ": ");
+    ///
+    /// assert_eq!("HTTP/1.1 200 OK\r\nContent-Length: 0", &welder.weld());
+    /// ```
+    pub fn elems_pairs<I, K, V, H>(mut self, pairs: I, mut kv_glue: H) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        H: Glue<T>,
+        T: Weldable<K> + Weldable<V>,
+    {
+        for (key, value) in pairs {
+            self.glue.apply(&mut self.welded);
+            self.welded.weld_elem(key);
+            kv_glue.apply(&mut self.welded);
+            self.welded.weld_elem(value);
+            self.glues += 1;
+            self.count_elems(1);
+        }
+        self
+    }
+
+    /// Push each key and value like `elems_pairs`, the pairs are sorted by key first
+    /// so that the output of an unordered map is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use welder::Welder;
+    ///
+    /// let mut env = HashMap::new();
+    /// env.insert("PATH", "/bin");
+    /// env.insert("HOME", "/root");
+    /// env.insert("LANG", "C");
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start('\n', "[env]").elems_sorted_pairs(&env, '=');
+    ///
+    /// assert_eq!("[env]\nHOME=/root\nLANG=C\nPATH=/bin", &welder.weld());
+    /// ```
+    pub fn elems_sorted_pairs<I, K, V, H>(self, pairs: I, kv_glue: H) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Ord,
+        H: Glue<T>,
+        T: Weldable<K> + Weldable<V>,
+    {
+        let mut pairs: Vec<_> = pairs.into_iter().collect();
+        pairs.sort_by(|(a, _), (b, _)| a.cmp(b));
+        self.elems_pairs(pairs, kv_glue)
+    }
+
     /// It will add a glue only to right of the element.
     ///
     /// # Examples
@@ -216,10 +1343,9 @@ where
     /// ```
     pub fn elem_glue_right<E>(mut self, elem: E) -> Self
     where
-        T: Extend<E>
+        T: Weldable<E>
     {
-        self.welded.extend(once(elem));
-        self.welded.extend(once(self.glue.clone()));
+        self.push_elem_glue_right(elem);
         self
     }
 
@@ -240,11 +1366,9 @@ where
     pub fn elems_glue_right<I>(mut self, elems: I) -> Self
     where
         I: IntoIterator,
-        T: Extend<I::Item>,
+        T: Weldable<I::Item>,
     {
-        for elem in elems {
-            self = self.elem_glue_right(elem)
-        }
+        self.push_elems_glue_right(elems);
         self
     }
 
@@ -265,10 +1389,9 @@ where
     /// ```
     pub fn elem_glue_left<E>(mut self, elem: E) -> Self
     where
-        T: Extend<E>
+        T: Weldable<E>
     {
-        self.welded.extend(once(self.glue.clone()));
-        self.welded.extend(once(elem));
+        self.push_elem_glue_left(elem);
         self
     }
 
@@ -290,11 +1413,9 @@ where
     pub fn elems_glue_left<I>(mut self, elems: I) -> Self
     where
         I: IntoIterator,
-        T: Extend<I::Item>,
+        T: Weldable<I::Item>,
     {
-        for elem in elems {
-            self = self.elem_glue_left(elem)
-        }
+        self.push_elems_glue_left(elems);
         self
     }
 
@@ -315,11 +1436,9 @@ where
     /// ```
     pub fn elem_glue_both<E>(mut self, elem: E) -> Self
     where
-        T: Extend<E>
+        T: Weldable<E>
     {
-        self.welded.extend(once(self.glue.clone()));
-        self.welded.extend(once(elem));
-        self.welded.extend(once(self.glue.clone()));
+        self.push_elem_glue_both(elem);
         self
     }
 
@@ -340,18 +1459,86 @@ where
     pub fn elems_glue_both<I>(mut self, elems: I) -> Self
     where
         I: IntoIterator,
-        T: Extend<I::Item>,
+        T: Weldable<I::Item>,
     {
-        for elem in elems {
-            self = self.elem_glue_both(elem)
-        }
+        self.push_elems_glue_both(elems);
         self
     }
 }
 
+/// Every element is added with a glue in front of it, like `Welder::elems`.
+impl<G, T, E> Extend<E> for Welder<G, T>
+where
+    G: Glue<T>,
+    T: Weldable<E>,
+{
+    fn extend<I: IntoIterator<Item = E>>(&mut self, iter: I) {
+        self.push_elems(iter);
+    }
+}
+
+/// A `Welder` can be given where a `String` is expected, it is welded.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+/// use welder::Welder;
+///
+/// fn greet<S: Into<String>>(name: S) -> String {
+///     format!("Hello {}!", name.into())
+/// }
+///
+/// let welder: Welder<_, String> = Welder::with_start(' ', "John").elem("Doe");
+/// assert_eq!("Hello John Doe!", &greet(welder));
+///
+/// let welder: Welder<_, String> = Welder::with_start(' ', "a").elem("b");
+/// let boxed: Box<str> = welder.into();
+/// assert_eq!("a b", &*boxed);
+///
+/// let welder: Welder<_, String> = Welder::with_start(' ', "a");
+/// let cow: Cow<str> = welder.into();
+/// assert_eq!("a", &cow);
+/// ```
+impl<G> From<Welder<G, String>> for String {
+    fn from(welder: Welder<G, String>) -> String {
+        welder.weld()
+    }
+}
+
+impl<G> From<Welder<G, String>> for Box<str> {
+    fn from(welder: Welder<G, String>) -> Box<str> {
+        welder.weld().into_boxed_str()
+    }
+}
+
+impl<'a, G> From<Welder<G, String>> for Cow<'a, str> {
+    fn from(welder: Welder<G, String>) -> Cow<'a, str> {
+        Cow::Owned(welder.weld())
+    }
+}
+
+/// A `Welder` can be given where a `Vec` is expected, it is welded.
+///
+/// # Examples
+///
+/// ```
+/// use welder::Welder;
+///
+/// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 1).elem(2);
+///
+/// let vec: Vec<_> = welder.into();
+/// assert_eq!(vec![1, 0, 2], vec);
+/// ```
+impl<G, T> From<Welder<G, Vec<T>>> for Vec<T> {
+    fn from(welder: Welder<G, Vec<T>>) -> Vec<T> {
+        welder.weld()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Welder;
+    use super::{StringWelder, VecWelder, Welder};
 
     #[test]
     fn string_welder() {
@@ -415,4 +1602,58 @@ mod tests {
 
         assert_eq!("foo bar baz boat", &string);
     }
+
+    #[test]
+    fn welder_is_an_extend_sink() {
+        let mut welder: Welder<_, String> = Welder::with_start(", ", "a");
+        welder.extend(vec!["b", "c"]);
+        vec!["d"].into_iter().for_each(|e| welder.extend(Some(e)));
+
+        assert_eq!("a, b, c, d", &welder.weld());
+    }
+
+    #[test]
+    fn welders_compare_glue_and_output() {
+        let welder: Welder<_, String> = Welder::with_start(", ", "a").elem("b");
+        let copy = welder.clone();
+
+        assert_eq!(welder, copy);
+        assert_eq!(welder, Welder::from_parts(", ", String::from("a, b")));
+        assert_ne!(welder, copy.elem("c"));
+        assert_eq!(Welder::<&str, String>::new(""), Welder::default());
+    }
+
+    #[test]
+    fn aliases_spell_out_the_target() {
+        let welder: StringWelder<_> = Welder::with_start(", ", "a").elem("b");
+        assert_eq!("a, b", &welder.weld());
+
+        let welder: VecWelder<_> = Welder::with_start(0, 1).elem(2);
+        assert_eq!(vec![1, 0, 2], welder.weld());
+    }
+
+    #[test]
+    fn counts_follow_restore() {
+        let welder: Welder<_, String> = Welder::with_start(' ', "foo").elems_no_glue(vec!["a", "b"]);
+        let snapshot = welder.snapshot();
+
+        let welder = welder.elem_glue_both("bar").restore(snapshot);
+
+        assert_eq!(3, welder.len());
+        assert_eq!(0, welder.glue_count());
+    }
+    #[test]
+    fn restore_puts_the_glue_state_back() {
+        let welder: Welder<_, String> = Welder::with_first_glue('?', '&').elem_no_glue("/s");
+        let snapshot = welder.snapshot();
+
+        let welder = welder.elem("a=1").restore(snapshot).elem("b=2");
+        assert_eq!("/s?b=2", &welder.weld());
+
+        let mut welder: Welder<_, String> = Welder::with_first_glue('?', '&').elem_no_glue("/s");
+        let ahead = welder.clone().elem("a=1").snapshot();
+
+        welder.rollback(ahead);
+        assert_eq!("/s?b=2", &welder.elem("b=2").weld());
+    }
 }
@@ -0,0 +1,228 @@
+use std::error::Error;
+use std::fmt;
+
+use {Glue, Snapshot, Truncate, Weldable, Welder};
+
+/// The error returned when a `Limited` welder output exceeds its limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitExceeded {
+    /// The maximum length the output was allowed to reach.
+    pub limit: usize,
+    /// The length the output reached.
+    pub len: usize,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "welded output length {} exceeds the limit of {}", self.len, self.limit)
+    }
+}
+
+impl Error for LimitExceeded {}
+
+/// A `Welder` that refuses to produce an output longer than a limit.
+///
+/// It is created by the `limit_len` method of a `Welder`,
+/// the length is counted in bytes for a `String` and in items for a `Vec`.
+///
+/// An element that would make the output exceed the limit is refused
+/// and the output, and the state of the glue, are left as they were before it,
+/// the elements accepted are kept.
+pub struct Limited<G, T> {
+    welder: Welder<G, T>,
+    limit: usize,
+}
+
+impl<G, T: Truncate> Welder<G, T> {
+    /// Limit the length the output can reach, in bytes
+    /// for a `String` and in items for a `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    ///
+    /// let mut welder = welder.limit_len(7);
+    /// welder.try_elem("bar").unwrap();
+    /// assert_eq!("foo bar", &welder.try_weld().unwrap());
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 12);
+    ///
+    /// let mut welder = welder.limit_len(3);
+    /// welder.try_elem(14).unwrap();
+    /// assert!(welder.try_elem(16).is_err());
+    /// ```
+    pub fn limit_len(self, limit: usize) -> Limited<G, T> {
+        Limited { welder: self, limit }
+    }
+}
+
+impl<G, T: Truncate> Limited<G, T> {
+    fn check(&self) -> Result<(), LimitExceeded> {
+        let len = self.welder.welded.len();
+        if len > self.limit {
+            return Err(LimitExceeded { limit: self.limit, len })
+        }
+        Ok(())
+    }
+
+    /// Add the element once the glue, if any, has been pushed, the length
+    /// of the element is checked before welding it when the target knows it.
+    fn push_checked<E>(&mut self, snapshot: Snapshot<G>, elem: E) -> Result<&mut Self, LimitExceeded>
+    where
+        T: Weldable<E>
+    {
+        let len = self.welder.welded.len();
+        if let Some(elem_len) = self.welder.welded.len_hint(&elem) {
+            let len = len.saturating_add(elem_len);
+            if len > self.limit {
+                self.welder.rollback(snapshot);
+                return Err(LimitExceeded { limit: self.limit, len })
+            }
+        }
+
+        self.welder.push_elem_no_glue(elem);
+        if let Err(error) = self.check() {
+            self.welder.rollback(snapshot);
+            return Err(error)
+        }
+        Ok(self)
+    }
+
+    /// Retrieve the accumulated values if they do not exceed the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    /// let welder = welder.elem("bar").limit_len(5);
+    ///
+    /// let error = welder.try_weld().unwrap_err();
+    /// assert_eq!(7, error.len);
+    /// ```
+    pub fn try_weld(self) -> Result<T, LimitExceeded> {
+        self.check().map(|_| self.welder.weld())
+    }
+
+    /// Retrieve the underlying `Welder`, removing the limit.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Add the element without any glue, failing if the output would exceed the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    /// let mut welder = welder.limit_len(6);
+    ///
+    /// welder.try_elem_no_glue("bar").unwrap();
+    /// assert!(welder.try_elem_no_glue("baz").is_err());
+    /// assert_eq!("foobar", &welder.try_weld().unwrap());
+    /// ```
+    pub fn try_elem_no_glue<E>(&mut self, elem: E) -> Result<&mut Self, LimitExceeded>
+    where
+        G: Clone,
+        T: Weldable<E>,
+    {
+        let snapshot = self.welder.snapshot();
+        self.push_checked(snapshot, elem)
+    }
+}
+
+impl<G, T> Limited<G, T>
+where
+    G: Glue<T> + Clone,
+    T: Truncate,
+{
+    /// Add the element with a glue in front of it,
+    /// failing if the output would exceed the limit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(' ');
+    /// let mut welder = welder.limit_len(8);
+    ///
+    /// welder.try_elem("foo").and_then(|w| w.try_elem("bar")).unwrap();
+    /// assert_eq!(Some(12), welder.try_elem("baz").err().map(|e| e.len));
+    /// assert_eq!(" foo bar", &welder.try_weld().unwrap());
+    /// ```
+    pub fn try_elem<E>(&mut self, elem: E) -> Result<&mut Self, LimitExceeded>
+    where
+        T: Weldable<E>
+    {
+        let snapshot = self.welder.snapshot();
+        self.welder.glue.apply(&mut self.welder.welded);
+        self.welder.glues += 1;
+        self.push_checked(snapshot, elem)
+    }
+
+    /// Add each element with a glue in front of it,
+    /// failing as soon as the output would exceed the limit.
+    ///
+    /// The elements are pulled from the iterator one by one,
+    /// none of them are consumed after the one refused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::new(0);
+    /// let mut welder = welder.limit_len(1000);
+    ///
+    /// let endless = (1..).map(|x| x * 2);
+    /// assert_eq!(Some(1002), welder.try_elems(endless).err().map(|e| e.len));
+    /// assert_eq!(1000, welder.into_inner().weld().len());
+    /// ```
+    pub fn try_elems<I>(&mut self, elems: I) -> Result<&mut Self, LimitExceeded>
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self.try_elem(elem)?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    #[test]
+    fn oversized_elem_is_refused_before_welding() {
+        let welder: Welder<_, String> = Welder::with_start(' ', "ok");
+        let mut welder = welder.limit_len(8);
+
+        let huge = "x".repeat(1000);
+        let error = welder.try_elem(huge.as_str()).err().unwrap();
+
+        assert_eq!(1003, error.len);
+        let welder = welder.into_inner();
+        assert_eq!(1, welder.len());
+        assert_eq!("ok", &welder.weld());
+    }
+
+    #[test]
+    fn refused_elem_does_not_move_the_glue() {
+        let welder: Welder<_, String> = Welder::with_first_glue('?', '&').elem_no_glue("/s");
+        let mut welder = welder.limit_len(8);
+
+        assert!(welder.try_elem("toolongparam").is_err());
+        welder.try_elem("a=1").unwrap();
+
+        assert_eq!("/s?a=1", &welder.try_weld().unwrap());
+    }
+}
@@ -0,0 +1,121 @@
+/// Implement `Extend` and `Weldable` for the common byte and string items
+/// on a type that has an inherent `fn write(&mut self, bytes: &[u8])` method.
+///
+/// It is only used by targets behind optional features.
+#[allow(unused_macros)]
+macro_rules! impl_extend_bytes {
+    ($ty:ty) => {
+        impl ::std::iter::Extend<u8> for $ty {
+            fn extend<I: IntoIterator<Item = u8>>(&mut self, iter: I) {
+                let bytes: Vec<_> = iter.into_iter().collect();
+                self.write(&bytes);
+            }
+        }
+
+        impl<'a> ::std::iter::Extend<&'a u8> for $ty {
+            fn extend<I: IntoIterator<Item = &'a u8>>(&mut self, iter: I) {
+                self.extend(iter.into_iter().cloned())
+            }
+        }
+
+        impl<'a> ::std::iter::Extend<&'a [u8]> for $ty {
+            fn extend<I: IntoIterator<Item = &'a [u8]>>(&mut self, iter: I) {
+                for bytes in iter {
+                    self.write(bytes);
+                }
+            }
+        }
+
+        impl ::std::iter::Extend<Vec<u8>> for $ty {
+            fn extend<I: IntoIterator<Item = Vec<u8>>>(&mut self, iter: I) {
+                for bytes in iter {
+                    self.write(&bytes);
+                }
+            }
+        }
+
+        impl ::std::iter::Extend<char> for $ty {
+            fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
+                let mut buf = [0; 4];
+                for c in iter {
+                    self.write(c.encode_utf8(&mut buf).as_bytes());
+                }
+            }
+        }
+
+        impl<'a> ::std::iter::Extend<&'a str> for $ty {
+            fn extend<I: IntoIterator<Item = &'a str>>(&mut self, iter: I) {
+                for s in iter {
+                    self.write(s.as_bytes());
+                }
+            }
+        }
+
+        impl ::std::iter::Extend<String> for $ty {
+            fn extend<I: IntoIterator<Item = String>>(&mut self, iter: I) {
+                for s in iter {
+                    self.write(s.as_bytes());
+                }
+            }
+        }
+
+        impl_extend_bytes!(@weldable $ty, u8 => |b| &[b]);
+        impl_extend_bytes!(@weldable $ty, &'a u8 => |b| &[*b]);
+        impl_extend_bytes!(@weldable $ty, &'a [u8] => |b| b);
+        impl_extend_bytes!(@weldable $ty, Vec<u8> => |b| &b);
+        impl_extend_bytes!(@weldable $ty, char => |c| c.encode_utf8(&mut [0; 4]).as_bytes());
+        impl_extend_bytes!(@weldable $ty, &'a str => |s| s.as_bytes());
+        impl_extend_bytes!(@weldable $ty, String => |s| s.as_bytes());
+    };
+    (@weldable $ty:ty, $item:ty => |$elem:ident| $bytes:expr) => {
+        impl<'a> ::Weldable<$item> for $ty {
+            fn weld_elem(&mut self, $elem: $item) {
+                self.write($bytes);
+            }
+        }
+    };
+}
+
+/// Weld a fixed list of expressions with a glue between them,
+/// the first expression is the start of the `Welder`.
+///
+/// The glue can also be given as `glue = ...`, optionally followed
+/// by a `terminator = ...` appended after the last element.
+///
+/// # Examples
+///
+/// ```
+/// #[macro_use]
+/// extern crate welder;
+///
+/// # fn main() {
+/// let name = String::from("b");
+///
+/// let string: String = weld!(", "; "a", name, "c");
+/// assert_eq!("a, b, c", &string);
+///
+/// let string: String = weld!(glue = '\n', terminator = ";"; "x = 1;", "y = 2;");
+/// assert_eq!("x = 1;\ny = 2;;", &string);
+///
+/// let vec: Vec<i32> = weld!(glue = 0; 1, 2 + 2);
+/// assert_eq!(vec![1, 0, 4], vec);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! weld {
+    (glue = $glue:expr, terminator = $terminator:expr; $($elem:expr),* $(,)*) => {
+        $crate::weld!(@welder $glue; $($elem),*).weld_with_terminator($terminator)
+    };
+    (glue = $glue:expr; $($elem:expr),* $(,)*) => {
+        $crate::weld!(@welder $glue; $($elem),*).weld()
+    };
+    (@welder $glue:expr;) => {
+        $crate::Welder::new($glue)
+    };
+    (@welder $glue:expr; $first:expr $(, $elem:expr)*) => {
+        $crate::Welder::with_start($glue, $first)$(.elem($elem))*
+    };
+    ($glue:expr; $($elem:expr),* $(,)*) => {
+        $crate::weld!(@welder $glue; $($elem),*).weld()
+    };
+}
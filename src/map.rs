@@ -0,0 +1,81 @@
+use {Glue, Weldable, Welder};
+
+/// A `Welder` that passes every element through a function before adding it.
+///
+/// It is created by the `map_elems` method of a `Welder`.
+pub struct MappedElems<G, T, F> {
+    welder: Welder<G, T>,
+    f: F,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Transform each of the next elements with the function before adding it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder = Welder::new(", ").map_elems(|s: &str| s.trim().to_lowercase());
+    ///
+    /// let welder = welder.elem_no_glue(" Foo ").elems(vec!["BAR", "  baz"]);
+    ///
+    /// let string: String = welder.weld();
+    /// assert_eq!("foo, bar, baz", &string);
+    /// ```
+    pub fn map_elems<F>(self, f: F) -> MappedElems<G, T, F> {
+        MappedElems { welder: self, f }
+    }
+}
+
+impl<G, T, F> MappedElems<G, T, F> {
+    /// Retrieve the `Welder`, the next elements will not be transformed anymore.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Retrieve the accumulated values, see `Welder::weld`.
+    pub fn weld(self) -> T {
+        self.welder.weld()
+    }
+
+    /// Add the transformed element without any glue, see `Welder::elem_no_glue`.
+    pub fn elem_no_glue<E, U>(mut self, elem: E) -> Self
+    where
+        F: FnMut(E) -> U,
+        T: Weldable<U>,
+    {
+        let elem = (self.f)(elem);
+        self.welder.push_elem_no_glue(elem);
+        self
+    }
+}
+
+impl<G, T, F> MappedElems<G, T, F>
+where
+    G: Glue<T>,
+{
+    /// Add the transformed element with a glue in front of it, see `Welder::elem`.
+    pub fn elem<E, U>(mut self, elem: E) -> Self
+    where
+        F: FnMut(E) -> U,
+        T: Weldable<U>,
+    {
+        let elem = (self.f)(elem);
+        self.welder.push_elem(elem);
+        self
+    }
+
+    /// Add each transformed element with a glue in front of it, see `Welder::elems`.
+    pub fn elems<I, U>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        F: FnMut(I::Item) -> U,
+        T: Weldable<U>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
@@ -0,0 +1,378 @@
+use std::{fmt, slice, vec};
+
+use {Glue, Weldable};
+
+enum Piece<E> {
+    Glue,
+    Elem(E),
+}
+
+/// A `Welder` that remembers the result of its last `weld`.
+///
+/// Welding again without adding any element returns the cached value,
+/// adding an element invalidates it and the next `weld` rebuilds it.
+pub struct MemoWelder<G, E, T> {
+    glue: G,
+    pieces: Vec<Piece<E>>,
+    cached: Option<T>,
+}
+
+impl<G, E, T> MemoWelder<G, E, T> {
+    /// Create an empty `MemoWelder` just by defining the glue used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, &str, String>::new(' ');
+    ///
+    /// assert_eq!("", welder.weld());
+    /// ```
+    pub fn new(glue: G) -> Self {
+        MemoWelder {
+            glue,
+            pieces: Vec::new(),
+            cached: None,
+        }
+    }
+
+    /// Create a `MemoWelder` with a first value and the glue it will use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(' ', "foo");
+    ///
+    /// assert_eq!("foo", welder.weld());
+    /// ```
+    pub fn with_start(glue: G, start: E) -> Self {
+        let mut welder = MemoWelder::new(glue);
+        welder.push_elem_no_glue(start);
+        welder
+    }
+
+    /// Retrieve the accumulated values, only welding them
+    /// if an element has been added since the last call.
+    ///
+    /// Each rebuild starts from a clone of the glue given at construction,
+    /// a glue that keeps a state gives the same output every time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(' ', "foo");
+    ///
+    /// welder.push_elem("bar");
+    /// assert_eq!("foo bar", welder.weld());
+    /// assert!(welder.is_cached());
+    ///
+    /// welder.push_elem("baz");
+    /// assert!(!welder.is_cached());
+    /// assert_eq!("foo bar baz", welder.weld());
+    /// ```
+    pub fn weld(&mut self) -> &T
+    where
+        G: Glue<T> + Clone,
+        E: Clone,
+        T: Default + Weldable<E>,
+    {
+        let MemoWelder { ref glue, ref pieces, ref mut cached } = *self;
+        cached.get_or_insert_with(|| {
+            let mut glue = glue.clone();
+            let mut welded = T::default();
+            for piece in pieces {
+                match *piece {
+                    Piece::Glue => glue.apply(&mut welded),
+                    Piece::Elem(ref elem) => welded.weld_elem(elem.clone()),
+                }
+            }
+            welded
+        })
+    }
+
+    /// Retrieve an adaptor that formats the elements and the glues
+    /// when it is displayed, without welding them into a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(", ", 12);
+    /// welder.push_elem(14);
+    ///
+    /// assert_eq!("values: [12, 14]", format!("values: [{}]", welder.weld_display()));
+    /// assert!(!welder.is_cached());
+    /// ```
+    pub fn weld_display(&self) -> WeldDisplay<'_, G, E>
+    where
+        G: fmt::Display,
+        E: fmt::Display,
+    {
+        WeldDisplay { glue: &self.glue, pieces: &self.pieces }
+    }
+
+    /// Iterate over the elements added, in order, without the glues.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(", ", "foo");
+    /// welder.push_elems(vec!["bar", "baz"]);
+    ///
+    /// let len: usize = welder.iter().map(|elem| elem.len()).sum();
+    /// assert_eq!(9, len);
+    /// ```
+    pub fn iter(&self) -> Elems<'_, E> {
+        Elems { pieces: self.pieces.iter() }
+    }
+
+    /// Returns `true` if the next `weld` will return the cached value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(' ', "foo");
+    /// assert!(!welder.is_cached());
+    ///
+    /// welder.weld();
+    /// assert!(welder.is_cached());
+    /// ```
+    pub fn is_cached(&self) -> bool {
+        self.cached.is_some()
+    }
+
+    /// Drop the cached value, forcing the next `weld` to rebuild it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(' ', "foo");
+    /// welder.weld();
+    ///
+    /// welder.invalidate();
+    /// assert!(!welder.is_cached());
+    /// ```
+    pub fn invalidate(&mut self) {
+        self.cached = None;
+    }
+
+    /// This function will add the element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::with_start(' ', "foo");
+    ///
+    /// welder.push_elem_no_glue("bar").push_elem_no_glue("baz");
+    ///
+    /// assert_eq!("foobarbaz", welder.weld());
+    /// ```
+    pub fn push_elem_no_glue(&mut self, elem: E) -> &mut Self {
+        self.invalidate();
+        self.pieces.push(Piece::Elem(elem));
+        self
+    }
+
+    /// Push a new value to the already accumulated values.
+    /// This function will add a glue element in front of the element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::new(' ');
+    ///
+    /// welder.push_elem("foo").push_elem("bar");
+    ///
+    /// assert_eq!(" foo bar", welder.weld());
+    /// ```
+    pub fn push_elem(&mut self, elem: E) -> &mut Self {
+        self.invalidate();
+        self.pieces.push(Piece::Glue);
+        self.pieces.push(Piece::Elem(elem));
+        self
+    }
+
+    /// Push all elements to the already accumulated values.
+    /// This function will add a glue in front of each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::MemoWelder;
+    ///
+    /// let mut welder = MemoWelder::<_, _, String>::new(' ');
+    ///
+    /// welder.push_elems(vec!["foo", "bar"]);
+    ///
+    /// assert_eq!(" foo bar", welder.weld());
+    /// ```
+    pub fn push_elems<I>(&mut self, elems: I) -> &mut Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for elem in elems {
+            self.push_elem(elem);
+        }
+        self
+    }
+}
+
+/// An iterator over the elements of a `MemoWelder`.
+///
+/// It is created by the `iter` method of a `MemoWelder`.
+pub struct Elems<'a, E: 'a> {
+    pieces: slice::Iter<'a, Piece<E>>,
+}
+
+impl<'a, E> Iterator for Elems<'a, E> {
+    type Item = &'a E;
+
+    fn next(&mut self) -> Option<&'a E> {
+        loop {
+            match *self.pieces.next()? {
+                Piece::Glue => continue,
+                Piece::Elem(ref elem) => return Some(elem),
+            }
+        }
+    }
+}
+
+/// An iterator that moves the elements out of a `MemoWelder`.
+pub struct IntoElems<E> {
+    pieces: vec::IntoIter<Piece<E>>,
+}
+
+impl<E> Iterator for IntoElems<E> {
+    type Item = E;
+
+    fn next(&mut self) -> Option<E> {
+        loop {
+            match self.pieces.next()? {
+                Piece::Glue => continue,
+                Piece::Elem(elem) => return Some(elem),
+            }
+        }
+    }
+}
+
+impl<'a, G, E, T> IntoIterator for &'a MemoWelder<G, E, T> {
+    type Item = &'a E;
+    type IntoIter = Elems<'a, E>;
+
+    fn into_iter(self) -> Elems<'a, E> {
+        self.iter()
+    }
+}
+
+/// The elements are moved out, in order, without the glues.
+///
+/// # Examples
+///
+/// ```
+/// use welder::MemoWelder;
+///
+/// let mut welder = MemoWelder::<_, _, String>::with_start(", ", String::from("foo"));
+/// welder.push_elem(String::from("bar"));
+///
+/// let elems: Vec<String> = welder.into_iter().collect();
+/// assert_eq!(vec!["foo", "bar"], elems);
+/// ```
+impl<G, E, T> IntoIterator for MemoWelder<G, E, T> {
+    type Item = E;
+    type IntoIter = IntoElems<E>;
+
+    fn into_iter(self) -> IntoElems<E> {
+        IntoElems { pieces: self.pieces.into_iter() }
+    }
+}
+
+/// An adaptor that formats the elements and the glues of a `MemoWelder`.
+///
+/// It is created by the `weld_display` method of a `MemoWelder`.
+///
+/// The width, fill, alignment and precision given to the formatter
+/// are not supported, each piece is written with its plain `Display`.
+pub struct WeldDisplay<'a, G: 'a, E: 'a> {
+    glue: &'a G,
+    pieces: &'a [Piece<E>],
+}
+
+impl<'a, G: fmt::Display, E: fmt::Display> fmt::Display for WeldDisplay<'a, G, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for piece in self.pieces {
+            match *piece {
+                Piece::Glue => write!(f, "{}", self.glue)?,
+                Piece::Elem(ref elem) => write!(f, "{}", elem)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MemoWelder;
+    use FirstGlue;
+
+    #[test]
+    fn memo_welder_returns_cached_value() {
+        let mut welder = MemoWelder::<_, _, String>::with_start(' ', "foo");
+        welder.push_elem("bar");
+
+        let first = welder.weld() as *const String;
+        let second = welder.weld() as *const String;
+
+        assert_eq!(first, second);
+        assert_eq!("foo bar", welder.weld());
+    }
+
+    #[test]
+    fn memo_welder_vec_invalidated() {
+        let mut welder = MemoWelder::<_, _, Vec<_>>::with_start(0, 12);
+        assert_eq!(&[12], welder.weld().as_slice());
+
+        welder.push_elems(vec![14, 16]);
+        assert_eq!(&[12, 0, 14, 0, 16], welder.weld().as_slice());
+    }
+
+    #[test]
+    fn memo_welder_rebuilds_with_fresh_glue() {
+        let glue = FirstGlue::new(": ", ", ");
+        let mut welder = MemoWelder::<_, _, String>::with_start(glue, "key");
+
+        welder.push_elem("a");
+        assert_eq!("key: a", welder.weld());
+
+        welder.push_elem("b");
+        assert_eq!("key: a, b", welder.weld());
+    }
+
+    #[test]
+    fn weld_display_ignores_the_formatter_options() {
+        let mut welder = MemoWelder::<_, _, String>::with_start(',', "a");
+        welder.push_elem("b");
+
+        assert_eq!("[a,b]", format!("[{:>8}]", welder.weld_display()));
+
+        let mut welder = MemoWelder::<_, _, String>::with_start(',', 1.5);
+        welder.push_elem(2.25);
+
+        assert_eq!("1.5,2.25", format!("{:.1}", welder.weld_display()));
+    }
+}
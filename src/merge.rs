@@ -0,0 +1,118 @@
+use std::iter::Sum;
+
+use {Glue, Weldable, Welder};
+
+impl<G, T> Welder<G, T>
+where
+    G: Glue<T>,
+    T: Default + Weldable<T>,
+{
+    /// Create a `Welder` from the outputs of all the welders,
+    /// the given glue is put between each of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let chunks: Vec<Welder<_, String>> = vec![
+    ///     Welder::with_start(',', "a").elem("b"),
+    ///     Welder::with_start(',', "c"),
+    /// ];
+    ///
+    /// let welder = Welder::concat(" | ", chunks);
+    ///
+    /// assert_eq!("a,b | c", &welder.weld());
+    /// ```
+    pub fn concat<H, I>(glue: G, welders: I) -> Self
+    where
+        I: IntoIterator<Item = Welder<H, T>>,
+    {
+        Welder::from_iter(glue, welders.into_iter().map(Welder::weld))
+    }
+}
+
+impl<G, T> Welder<G, T>
+where
+    G: Glue<T>,
+    T: Weldable<T>,
+{
+    /// Add the output of the other `Welder` as one element, with a glue in front of it,
+    /// the other `Welder` can use another glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let header: Welder<_, String> = Welder::with_start(", ", "id").elem("name");
+    /// let body: Welder<_, String> = Welder::with_start(", ", "1").elem("Alice");
+    ///
+    /// let document = Welder::new('\n').elem_no_glue("# users").append_welder(header).append_welder(body);
+    ///
+    /// let string: String = document.weld();
+    /// assert_eq!("# users\nid, name\n1, Alice", &string);
+    /// ```
+    pub fn append_welder<H>(self, other: Welder<H, T>) -> Self {
+        self.elem(other.weld())
+    }
+}
+
+impl<G, T> Welder<G, T>
+where
+    G: Glue<T>,
+{
+    /// Add a glue then run the closure with a `Welder` that writes directly
+    /// into the accumulated values, with its own glue, its output counts as one element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(", ");
+    ///
+    /// let welder = welder
+    ///     .elem_welder('|', |w| w.elem_no_glue("(a").elem("b").elem("c)"))
+    ///     .elem_welder('|', |w| w.elem_no_glue("(d").elem("e)"));
+    ///
+    /// assert_eq!(2, welder.len());
+    /// assert_eq!(", (a|b|c), (d|e)", &welder.weld());
+    /// ```
+    pub fn elem_welder<H, F>(mut self, glue: H, f: F) -> Self
+    where
+        F: for<'a> FnOnce(Welder<H, &'a mut T>) -> Welder<H, &'a mut T>,
+    {
+        self.glue.apply(&mut self.welded);
+        f(Welder::on(&mut self.welded, glue));
+        self.glues += 1;
+        self.count_elems(1);
+        self
+    }
+}
+
+/// The outputs of the welders are glued to the first one, with its glue.
+///
+/// # Examples
+///
+/// ```
+/// use welder::Welder;
+///
+/// let chunks = (0..3).map(|i| Welder::with_start(", ", i.to_string()).elem("x"));
+///
+/// let welder: Welder<_, String> = chunks.sum();
+///
+/// assert_eq!("0, x, 1, x, 2, x", &welder.weld());
+/// ```
+impl<G, T> Sum for Welder<G, T>
+where
+    G: Default + Glue<T>,
+    T: Default + Weldable<T>,
+{
+    fn sum<I: Iterator<Item = Self>>(mut iter: I) -> Self {
+        match iter.next() {
+            Some(first) => iter.fold(first, |welder, other| welder.elem(other.weld())),
+            None => Welder::default(),
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use std::fs::File;
+use std::io;
+
+use memmap2::MmapMut;
+
+use Welder;
+
+/// A target that writes the welded bytes directly into a memory-mapped file.
+///
+/// The file is mapped with an initial capacity and is grown, then mapped again,
+/// when the welded bytes do not fit anymore. Growing can fail, the first error
+/// encountered is kept and returned when the target is finished, nothing is written after it.
+pub struct MmapBuffer {
+    file: File,
+    map: MmapMut,
+    len: usize,
+    error: Option<io::Error>,
+}
+
+impl MmapBuffer {
+    /// Map the given file with room for `capacity` bytes,
+    /// the file must be opened for both reading and writing.
+    ///
+    /// The previous content of the file is overwritten.
+    pub fn new(file: File, capacity: usize) -> io::Result<Self> {
+        let capacity = capacity.max(1);
+        file.set_len(capacity as u64)?;
+        // The file is owned by the buffer during the whole life of the mapping,
+        // it is the responsibility of the caller to not modify it from elsewhere.
+        let map = unsafe { MmapMut::map_mut(&file)? };
+
+        Ok(MmapBuffer { file, map, len: 0, error: None })
+    }
+
+    /// The number of bytes welded into this buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no byte has been welded into this buffer.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of bytes the mapping can hold without growing the file.
+    pub fn capacity(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Grow the file and the mapping so that at least `additional` more bytes fit in it.
+    pub fn try_reserve(&mut self, additional: usize) -> io::Result<()> {
+        let needed = self.len.checked_add(additional).ok_or_else(|| {
+            io::Error::other("mapped capacity overflow")
+        })?;
+        if needed <= self.map.len() {
+            return Ok(())
+        }
+
+        let capacity = needed.max(self.map.len().saturating_mul(2));
+        self.map.flush()?;
+        self.file.set_len(capacity as u64)?;
+        // Same as in `new`, the file is still owned by the buffer.
+        self.map = unsafe { MmapMut::map_mut(&self.file)? };
+        Ok(())
+    }
+
+    /// Flush the mapping, truncate the file to the welded bytes and retrieve it.
+    pub fn finish(self) -> io::Result<File> {
+        if let Some(error) = self.error {
+            return Err(error)
+        }
+
+        self.map.flush()?;
+        drop(self.map);
+        self.file.set_len(self.len as u64)?;
+        Ok(self.file)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_some() {
+            return
+        }
+        if let Err(error) = self.try_reserve(bytes.len()) {
+            self.error = Some(error);
+            return
+        }
+
+        self.map[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+}
+
+impl_extend_bytes!(MmapBuffer);
+
+impl<G> Welder<G, MmapBuffer> {
+    /// Create an empty `Welder` that writes its output into the memory-mapped file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fs::OpenOptions;
+    /// use std::io::Read;
+    /// use welder::{Welder, MmapBuffer};
+    ///
+    /// # let path = std::env::temp_dir().join("welder-mapped-doc");
+    /// let file = OpenOptions::new().read(true).write(true).create(true).open(&path).unwrap();
+    /// let buffer = MmapBuffer::new(file, 4).unwrap();
+    ///
+    /// let welder = Welder::mapped(", ", buffer).elem("foo").elem("bar");
+    ///
+    /// let mut file = welder.weld_mapped().unwrap();
+    /// let mut string = String::new();
+    /// file.read_to_string(&mut string).unwrap();
+    ///
+    /// assert_eq!(", foo, bar", &string);
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    pub fn mapped(glue: G, buffer: MmapBuffer) -> Self {
+        Welder {
+            glue,
+            welded: buffer,
+            delta_start: 0,
+            elems: 0,
+            glues: 0,
+            chunk_open: false,
+        }
+    }
+
+    /// Retrieve the file the accumulated values have been written into.
+    pub fn weld_mapped(self) -> io::Result<File> {
+        self.welded.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::OpenOptions;
+    use std::io::Read;
+    use super::MmapBuffer;
+    use Welder;
+
+    #[test]
+    fn mmap_grows_and_truncates() {
+        let dir = ::std::env::temp_dir().join(format!("welder-mmap-{}", ::std::process::id()));
+        let file = OpenOptions::new().read(true).write(true).create(true).truncate(true).open(&dir).unwrap();
+
+        let buffer = MmapBuffer::new(file, 2).unwrap();
+        let welder = Welder::mapped('\n', buffer).elems_glue_right((0..100).map(|i| i.to_string()));
+
+        let mut file = welder.weld_mapped().unwrap();
+        let mut string = String::new();
+        file.read_to_string(&mut string).unwrap();
+        ::std::fs::remove_file(&dir).unwrap();
+
+        let expected: String = (0..100).map(|i| format!("{}\n", i)).collect();
+        assert_eq!(expected, string);
+    }
+}
@@ -0,0 +1,98 @@
+use {Capacity, Glue, Weldable, Welder};
+
+/// A `Welder` that calls a function after each element is added.
+///
+/// It is created by the `on_elem` method of a `Welder`, the function is given
+/// the index of the element and the length of the output once it is added.
+pub struct Observed<G, T, F> {
+    welder: Welder<G, T>,
+    callback: F,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Call the function with the index of each element added to the returned welder
+    /// and the length of the output, see `Capacity::len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut lengths = Vec::new();
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "a");
+    /// let welder = welder.on_elem(|index, len| lengths.push((index, len)));
+    ///
+    /// let string = welder.elems(vec!["bb", "ccc"]).weld();
+    ///
+    /// assert_eq!("a, bb, ccc", &string);
+    /// assert_eq!(vec![(1, 5), (2, 10)], lengths);
+    /// ```
+    pub fn on_elem<F>(self, callback: F) -> Observed<G, T, F>
+    where
+        F: FnMut(usize, usize),
+    {
+        Observed { welder: self, callback }
+    }
+}
+
+impl<G, T, F> Observed<G, T, F> {
+    /// Retrieve the `Welder`, the function will not be called anymore.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Retrieve the accumulated values, see `Welder::weld`.
+    pub fn weld(self) -> T {
+        self.welder.weld()
+    }
+}
+
+impl<G, T, F> Observed<G, T, F>
+where
+    T: Capacity,
+    F: FnMut(usize, usize),
+{
+    fn notify(&mut self) {
+        (self.callback)(self.welder.len() - 1, self.welder.welded.len());
+    }
+
+    /// Add the element without any glue, see `Welder::elem_no_glue`.
+    pub fn elem_no_glue<E>(mut self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        self.welder.push_elem_no_glue(elem);
+        self.notify();
+        self
+    }
+}
+
+impl<G, T, F> Observed<G, T, F>
+where
+    G: Glue<T>,
+    T: Capacity,
+    F: FnMut(usize, usize),
+{
+    /// Add the element with a glue in front of it, see `Welder::elem`.
+    pub fn elem<E>(mut self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        self.welder.push_elem(elem);
+        self.notify();
+        self
+    }
+
+    /// Add each element with a glue in front of it, see `Welder::elems`.
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
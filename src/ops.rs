@@ -0,0 +1,53 @@
+use std::ops::{Add, AddAssign};
+
+use {Glue, Weldable, Welder};
+
+/// `welder + elem` is the same as `welder.elem(elem)`, a `Welder`
+/// can be added to another one to glue its output to the end.
+///
+/// # Examples
+///
+/// ```
+/// use welder::Welder;
+///
+/// let welder: Welder<_, String> = Welder::with_start(", ", "a") + "b" + "c";
+/// assert_eq!("a, b, c", &welder.weld());
+///
+/// let left: Welder<_, String> = Welder::with_start(", ", "a") + "b";
+/// let right: Welder<_, String> = Welder::with_start('|', "x") + "y";
+/// assert_eq!("a, b, x|y", &(left + right).weld());
+/// ```
+impl<G, T, E> Add<E> for Welder<G, T>
+where
+    G: Glue<T>,
+    T: Weldable<E>,
+{
+    type Output = Self;
+
+    fn add(self, elem: E) -> Self {
+        self.elem(elem)
+    }
+}
+
+/// `welder += elem` is the same as `welder.push_elem(elem)`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::Welder;
+///
+/// let mut welder: Welder<_, Vec<_>> = Welder::with_start(0, 1);
+/// welder += 2;
+/// welder += 3;
+///
+/// assert_eq!(&[1, 0, 2, 0, 3], welder.weld().as_slice());
+/// ```
+impl<G, T, E> AddAssign<E> for Welder<G, T>
+where
+    G: Glue<T>,
+    T: Weldable<E>,
+{
+    fn add_assign(&mut self, elem: E) {
+        self.push_elem(elem);
+    }
+}
@@ -0,0 +1,146 @@
+use std::borrow::Cow;
+use std::ptr;
+
+use {Glue, Weldable, Welder};
+
+type Hook<E, Er> = Box<dyn for<'a> FnMut(&'a E) -> Result<Cow<'a, E>, Er>>;
+
+/// A `Welder` that passes every element through a chain of hooks before adding it,
+/// a hook can rewrite the element or reject it with an error.
+///
+/// It is created by the `pipeline` method of a `Welder`.
+pub struct Pipeline<G, T, E: ?Sized + ToOwned + 'static, Er> {
+    welder: Welder<G, T>,
+    hooks: Vec<Hook<E, Er>>,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Pass the elements added with the `try_elem*` methods of the returned welder
+    /// through the hooks registered with `Pipeline::hook`, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(',');
+    ///
+    /// let mut welder = welder.pipeline()
+    ///     .hook(|s: &str| Ok(Cow::Borrowed(s.trim())))
+    ///     .hook(|s: &str| if s.is_empty() { Err("empty field") } else { Ok(Cow::Borrowed(s)) })
+    ///     .hook(|s: &str| Ok(if s.contains(',') { Cow::Owned(format!("{:?}", s)) } else { Cow::Borrowed(s) }));
+    ///
+    /// welder.try_elem_no_glue(" id ").unwrap().try_elem("a, b").unwrap();
+    /// assert_eq!(Some("empty field"), welder.try_elem("  ").err());
+    /// assert_eq!("id,\"a, b\"", &welder.weld());
+    /// ```
+    pub fn pipeline<E: ?Sized + ToOwned, Er>(self) -> Pipeline<G, T, E, Er> {
+        Pipeline { welder: self, hooks: Vec::new() }
+    }
+}
+
+impl<G, T, E, Er> Pipeline<G, T, E, Er>
+where
+    E: ?Sized + ToOwned + 'static,
+{
+    /// Register a hook run after the ones already registered.
+    pub fn hook<F>(mut self, hook: F) -> Self
+    where
+        F: for<'a> FnMut(&'a E) -> Result<Cow<'a, E>, Er> + 'static,
+    {
+        self.hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Retrieve the accumulated values, every one of them went through the hooks.
+    pub fn weld(self) -> T {
+        self.welder.weld()
+    }
+
+    /// Retrieve the underlying `Welder`, removing the hooks.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    fn run<'a>(&mut self, elem: &'a E) -> Result<Cow<'a, E>, Er> {
+        let mut elem = Cow::Borrowed(elem);
+        for hook in &mut self.hooks {
+            let next = match hook(&elem)? {
+                Cow::Borrowed(next) if ptr::eq(next, &*elem) => continue,
+                next => next.into_owned(),
+            };
+            elem = Cow::Owned(next);
+        }
+        Ok(elem)
+    }
+
+    /// Run the hooks then add the element without any glue.
+    pub fn try_elem_no_glue(&mut self, elem: &E) -> Result<&mut Self, Er>
+    where
+        T: for<'a> Weldable<&'a E>,
+    {
+        let elem = self.run(elem)?;
+        self.welder.push_elem_no_glue(&*elem);
+        Ok(self)
+    }
+}
+
+impl<G, T, E, Er> Pipeline<G, T, E, Er>
+where
+    G: Glue<T>,
+    E: ?Sized + ToOwned + 'static,
+{
+    /// Run the hooks then add the element with a glue in front of it.
+    pub fn try_elem(&mut self, elem: &E) -> Result<&mut Self, Er>
+    where
+        T: for<'a> Weldable<&'a E>,
+    {
+        let elem = self.run(elem)?;
+        self.welder.push_elem(&*elem);
+        Ok(self)
+    }
+
+    /// Run the hooks then add each element with a glue in front of it,
+    /// stopping at the first element rejected, the elements before it are kept.
+    pub fn try_elems<'a, I>(&mut self, elems: I) -> Result<&mut Self, Er>
+    where
+        I: IntoIterator<Item = &'a E>,
+        T: for<'b> Weldable<&'b E>,
+    {
+        for elem in elems {
+            self.try_elem(elem)?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use Welder;
+
+    #[test]
+    fn pipeline_hooks_run_in_order() {
+        let welder: Welder<_, String> = Welder::with_start(' ', "cmd");
+        let mut welder = welder.pipeline::<str, ()>()
+            .hook(|s| Ok(Cow::Owned(s.to_uppercase())))
+            .hook(|s| Ok(Cow::Borrowed(s.trim_start_matches('-'))));
+
+        welder.try_elems(vec!["--all", "-v"]).unwrap();
+
+        assert_eq!("cmd ALL V", &welder.weld());
+    }
+
+    #[test]
+    fn pipeline_rejection_keeps_the_welder() {
+        let welder: Welder<_, String> = Welder::new(',');
+        let mut welder = welder.pipeline::<str, usize>()
+            .hook(|s| if s.len() < 4 { Ok(Cow::Borrowed(s)) } else { Err(s.len()) });
+
+        assert_eq!(Some(7), welder.try_elems(vec!["a", "b", "toolong", "c"]).err());
+        welder.try_elem_no_glue("d").unwrap();
+
+        assert_eq!(",a,bd", &welder.weld());
+    }
+}
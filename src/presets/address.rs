@@ -0,0 +1,192 @@
+use std::error::Error;
+use std::fmt;
+
+use Welder;
+use super::join::join_elem;
+
+/// The error returned when an address of an `AddressList` could break the header,
+/// because it contains a control character, a whitespace, a comma or an angle bracket.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidAddress {
+    /// The rejected address.
+    pub address: String,
+}
+
+impl fmt::Display for InvalidAddress {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid email address {:?}", self.address)
+    }
+}
+
+impl Error for InvalidAddress {}
+
+/// A welder of email addresses, with optional display names, separated by `", "`,
+/// as found in the `To` and `Cc` headers of RFC 5322.
+///
+/// It is created by the `address_list` function.
+pub struct AddressList {
+    welder: Welder<&'static str, String>,
+}
+
+/// Create an empty list of email addresses.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::address_list;
+///
+/// let welder = address_list()
+///     .named("Alice Liddell", "alice@example.com").unwrap()
+///     .address("bob@example.com").unwrap();
+///
+/// assert_eq!("Alice Liddell <alice@example.com>, bob@example.com", &welder.weld());
+/// ```
+pub fn address_list() -> AddressList {
+    AddressList {
+        welder: Welder::new(", "),
+    }
+}
+
+/// Returns `true` if the character can be part of an atom, non-ASCII
+/// characters are allowed as specified in RFC 6532.
+fn is_atext(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-/=?^_`{|}~".contains(c) || !c.is_ascii()
+}
+
+/// Check that the address cannot end the mailbox or the header early.
+fn check_address(address: &str) -> Result<&str, InvalidAddress> {
+    let invalid = |c: char| c.is_control() || c.is_whitespace() || ",<>".contains(c);
+    if address.chars().any(invalid) {
+        return Err(InvalidAddress { address: address.to_owned() })
+    }
+    Ok(address)
+}
+
+/// Write the display name as a phrase of atoms when possible, as a quoted string otherwise.
+fn push_display_name(out: &mut String, name: &str) {
+    let name: String = name.chars().filter(|&c| c != '\r' && c != '\n').collect();
+    let atoms = name.split(' ').all(|atom| !atom.is_empty() && atom.chars().all(is_atext));
+
+    if atoms {
+        out.push_str(&name);
+        return
+    }
+
+    out.push('"');
+    for c in name.chars() {
+        if c == '"' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out.push('"');
+}
+
+impl AddressList {
+    fn push(mut self, mailbox: String) -> Self {
+        self.welder = join_elem(self.welder, mailbox);
+        self
+    }
+
+    /// Add an address without any display name.
+    ///
+    /// The address is rejected if it contains a control character,
+    /// like a line break, a whitespace, a comma or an angle bracket.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::address_list;
+    ///
+    /// assert!(address_list().address("a@example.com\r\nBcc: b@example.com").is_err());
+    /// ```
+    pub fn address<A: AsRef<str>>(self, address: A) -> Result<Self, InvalidAddress> {
+        let address = check_address(address.as_ref())?.to_owned();
+        Ok(self.push(address))
+    }
+
+    /// Add an address with a display name, the name is quoted if it contains
+    /// special characters, like commas or dots, and line breaks are removed from it.
+    ///
+    /// The address is rejected like with the `address` method.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::address_list;
+    ///
+    /// let welder = address_list()
+    ///     .named("Doe, John Q.", "john@example.com").unwrap()
+    ///     .named(r#"The "Boss""#, "boss@example.com").unwrap();
+    ///
+    /// let expected = r#""Doe, John Q." <john@example.com>, "The \"Boss\"" <boss@example.com>"#;
+    /// assert_eq!(expected, &welder.weld());
+    /// ```
+    pub fn named<N, A>(self, name: N, address: A) -> Result<Self, InvalidAddress>
+    where
+        N: AsRef<str>,
+        A: AsRef<str>,
+    {
+        let address = check_address(address.as_ref())?;
+        let mut mailbox = String::new();
+        push_display_name(&mut mailbox, name.as_ref());
+        mailbox.push_str(" <");
+        mailbox.push_str(address);
+        mailbox.push('>');
+        Ok(self.push(mailbox))
+    }
+
+    /// Retrieve the list of addresses.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{address_list, InvalidAddress};
+
+    #[test]
+    fn address_list_quotes_odd_spacing() {
+        let welder = address_list().named(" Alice", "a@example.com").unwrap();
+        let welder = welder.named("", "b@example.com").unwrap();
+
+        assert_eq!(r#"" Alice" <a@example.com>, "" <b@example.com>"#, &welder.weld());
+    }
+
+    #[test]
+    fn address_list_keeps_unicode_atoms() {
+        let welder = address_list().named("Zoë Ünal", "z@example.com").unwrap();
+
+        assert_eq!("Zoë Ünal <z@example.com>", &welder.weld());
+    }
+
+    fn rejected(address: &str) -> bool {
+        let invalid = Some(InvalidAddress { address: address.to_owned() });
+        address_list().address(address).err() == invalid
+            && address_list().named("Bob", address).err() == invalid
+    }
+
+    #[test]
+    fn address_list_rejects_line_breaks() {
+        assert!(rejected("a@x.com\r\nBcc: evil@x.com"));
+        assert!(rejected("a@x.com\n"));
+    }
+
+    #[test]
+    fn address_list_rejects_commas() {
+        assert!(rejected("a@x.com,c@x.com"));
+    }
+
+    #[test]
+    fn address_list_rejects_angle_brackets() {
+        assert!(rejected("b@x.com>c@x.com"));
+        assert!(rejected("<b@x.com"));
+    }
+
+    #[test]
+    fn address_list_rejects_whitespace() {
+        assert!(rejected("a b@x.com"));
+        assert!(rejected("a@x.com\t"));
+    }
+}
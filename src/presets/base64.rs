@@ -0,0 +1,68 @@
+const STANDARD: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const URL_SAFE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode the bytes with the standard alphabet of RFC 4648, with padding.
+pub fn encode_standard(out: &mut String, bytes: &[u8]) {
+    encode(out, bytes, STANDARD, true)
+}
+
+/// Encode the bytes with the URL and filename safe alphabet of RFC 4648, without padding.
+pub fn encode_url_safe(out: &mut String, bytes: &[u8]) {
+    encode(out, bytes, URL_SAFE, false)
+}
+
+fn encode(out: &mut String, bytes: &[u8], alphabet: &[u8; 64], pad: bool) {
+    for chunk in bytes.chunks(3) {
+        let b = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let n = (b[0] as usize) << 16 | (b[1] as usize) << 8 | b[2] as usize;
+
+        let chars = chunk.len() + 1;
+        for i in 0..4 {
+            if i < chars {
+                out.push(alphabet[n >> (18 - 6 * i) & 0x3f] as char);
+            } else if pad {
+                out.push('=');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{encode_standard, encode_url_safe};
+
+    #[test]
+    fn base64_url_safe_vectors() {
+        let vectors: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"f", "Zg"),
+            (b"fo", "Zm8"),
+            (b"foo", "Zm9v"),
+            (b"foob", "Zm9vYg"),
+            (b"\xfb\xff", "-_8"),
+        ];
+
+        for &(bytes, expected) in vectors {
+            let mut out = String::new();
+            encode_url_safe(&mut out, bytes);
+            assert_eq!(expected, out);
+        }
+    }
+
+    #[test]
+    fn base64_standard_vectors() {
+        let vectors: &[(&[u8], &str)] = &[
+            (b"", ""),
+            (b"f", "Zg=="),
+            (b"fo", "Zm8="),
+            (b"foo", "Zm9v"),
+            (b"\xfb\xff", "+/8="),
+        ];
+
+        for &(bytes, expected) in vectors {
+            let mut out = String::new();
+            encode_standard(&mut out, bytes);
+            assert_eq!(expected, out);
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use Welder;
+
+const ELLIPSIS: &str = "\u{2026}";
+
+/// A welder of breadcrumb segments, separated by `" > "`,
+/// that collapses the middle segments when the output is too wide.
+///
+/// It is created by the `breadcrumb` function.
+pub struct Breadcrumb {
+    segments: Vec<String>,
+    separator: String,
+    max_width: usize,
+}
+
+/// Create a welder of breadcrumb segments whose output should not exceed
+/// `max_width` characters.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::breadcrumb;
+///
+/// let welder = breadcrumb(20).elems(vec!["Home", "Docs", "API", "Welder"]);
+///
+/// assert_eq!("Home > \u{2026} > Welder", &welder.weld());
+/// ```
+pub fn breadcrumb(max_width: usize) -> Breadcrumb {
+    Breadcrumb {
+        segments: Vec::new(),
+        separator: String::from(" > "),
+        max_width,
+    }
+}
+
+impl Breadcrumb {
+    /// Use the given separator between the segments instead of `" > "`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::breadcrumb;
+    ///
+    /// let welder = breadcrumb(12).separator("/").elems(vec!["usr", "local", "share", "man"]);
+    ///
+    /// assert_eq!("usr/\u{2026}/man", &welder.weld());
+    /// ```
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Add a segment at the end of the breadcrumb.
+    pub fn elem<E: Into<String>>(mut self, segment: E) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+
+    /// Add each segment at the end of the breadcrumb.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::breadcrumb;
+    ///
+    /// let welder = breadcrumb(80).elems(vec!["Home", "Docs"]);
+    ///
+    /// assert_eq!("Home > Docs", &welder.weld());
+    /// ```
+    pub fn elems<I>(mut self, segments: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        for segment in segments {
+            self = self.elem(segment)
+        }
+        self
+    }
+
+    /// Retrieve the welded segments.
+    ///
+    /// When they do not fit in the maximum width, the first segment and as many
+    /// of the last ones as possible are kept and the others are collapsed into `"…"`.
+    /// The first and the last segments are always kept, even if they do not fit.
+    pub fn weld(self) -> String {
+        let sep_width = self.separator.chars().count();
+        let widths: Vec<_> = self.segments.iter().map(|s| s.chars().count()).collect();
+        let total = widths.iter().sum::<usize>() + sep_width * widths.len().saturating_sub(1);
+
+        if total <= self.max_width || self.segments.len() <= 2 {
+            return self.weld_segments(0)
+        }
+
+        // the first segment, the ellipsis and the last segment
+        let mut width = widths[0] + sep_width + 1 + sep_width + widths[widths.len() - 1];
+        let mut start = widths.len() - 1;
+        while start > 2 && width + widths[start - 1] + sep_width <= self.max_width {
+            start -= 1;
+            width += widths[start] + sep_width;
+        }
+
+        self.weld_segments(start)
+    }
+
+    /// Weld the first segment, an ellipsis if `start` is not zero,
+    /// then the segments from `start`.
+    fn weld_segments(self, start: usize) -> String {
+        let mut segments = self.segments.into_iter();
+        let welder = Welder::from_iter(self.separator.as_str(), segments.next());
+
+        let welder = if start != 0 {
+            welder.elem(ELLIPSIS).elems(segments.skip(start - 1))
+        } else {
+            welder.elems(segments)
+        };
+
+        welder.weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::breadcrumb;
+
+    #[test]
+    fn breadcrumb_keeps_trailing_segments_that_fit() {
+        let welder = breadcrumb(22).elems(vec!["a", "bbbbbbbbbb", "c", "d", "e"]);
+
+        assert_eq!("a > \u{2026} > c > d > e", &welder.weld());
+    }
+
+    #[test]
+    fn breadcrumb_never_drops_first_and_last() {
+        let welder = breadcrumb(3).elems(vec!["first", "middle", "last"]);
+        assert_eq!("first > \u{2026} > last", &welder.weld());
+
+        let welder = breadcrumb(3).elems(vec!["first", "last"]);
+        assert_eq!("first > last", &welder.weld());
+
+        assert_eq!("", &breadcrumb(3).weld());
+    }
+}
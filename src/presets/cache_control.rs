@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt;
+
+use Welder;
+use super::join::join_elem;
+use super::encode::is_token;
+
+/// The error returned when an extension directive of a `CacheControl` is not valid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDirective {
+    /// The name of the rejected directive.
+    pub name: String,
+}
+
+impl fmt::Display for InvalidDirective {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid Cache-Control directive {:?}", self.name)
+    }
+}
+
+impl Error for InvalidDirective {}
+
+/// A welder of `Cache-Control` directives separated by `", "`.
+///
+/// It is created by the `cache_control` function.
+pub struct CacheControl {
+    welder: Welder<&'static str, String>,
+}
+
+/// Create an empty list of `Cache-Control` directives.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::cache_control;
+///
+/// let welder = cache_control().public().max_age(3600).stale_while_revalidate(30);
+///
+/// assert_eq!("public, max-age=3600, stale-while-revalidate=30", &welder.weld());
+/// ```
+pub fn cache_control() -> CacheControl {
+    CacheControl {
+        welder: Welder::new(", "),
+    }
+}
+
+impl CacheControl {
+    fn push<D: AsRef<str>>(mut self, directive: D) -> Self {
+        self.welder = join_elem(self.welder, directive.as_ref());
+        self
+    }
+
+    /// Add the `max-age` directive, in seconds.
+    pub fn max_age(self, seconds: u32) -> Self {
+        self.push(format!("max-age={}", seconds))
+    }
+
+    /// Add the `s-maxage` directive, in seconds.
+    pub fn s_maxage(self, seconds: u32) -> Self {
+        self.push(format!("s-maxage={}", seconds))
+    }
+
+    /// Add the `stale-while-revalidate` directive, in seconds.
+    pub fn stale_while_revalidate(self, seconds: u32) -> Self {
+        self.push(format!("stale-while-revalidate={}", seconds))
+    }
+
+    /// Add the `stale-if-error` directive, in seconds.
+    pub fn stale_if_error(self, seconds: u32) -> Self {
+        self.push(format!("stale-if-error={}", seconds))
+    }
+
+    /// Add the `no-cache` directive.
+    pub fn no_cache(self) -> Self {
+        self.push("no-cache")
+    }
+
+    /// Add the `no-store` directive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::cache_control;
+    ///
+    /// let welder = cache_control().no_store().private();
+    ///
+    /// assert_eq!("no-store, private", &welder.weld());
+    /// ```
+    pub fn no_store(self) -> Self {
+        self.push("no-store")
+    }
+
+    /// Add the `no-transform` directive.
+    pub fn no_transform(self) -> Self {
+        self.push("no-transform")
+    }
+
+    /// Add the `must-revalidate` directive.
+    pub fn must_revalidate(self) -> Self {
+        self.push("must-revalidate")
+    }
+
+    /// Add the `proxy-revalidate` directive.
+    pub fn proxy_revalidate(self) -> Self {
+        self.push("proxy-revalidate")
+    }
+
+    /// Add the `public` directive.
+    pub fn public(self) -> Self {
+        self.push("public")
+    }
+
+    /// Add the `private` directive.
+    pub fn private(self) -> Self {
+        self.push("private")
+    }
+
+    /// Add the `immutable` directive.
+    pub fn immutable(self) -> Self {
+        self.push("immutable")
+    }
+
+    /// Add a directive this builder does not know about, with an optional value.
+    ///
+    /// The name must be a token, the value is quoted when it is not a token.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::cache_control;
+    ///
+    /// let welder = cache_control().no_cache().extension("community", Some("UCI team")).unwrap();
+    /// assert_eq!(r#"no-cache, community="UCI team""#, &welder.weld());
+    ///
+    /// assert!(cache_control().extension("max age", None).is_err());
+    /// ```
+    pub fn extension(self, name: &str, value: Option<&str>) -> Result<Self, InvalidDirective> {
+        let invalid = || InvalidDirective { name: name.to_owned() };
+        if !is_token(name) {
+            return Err(invalid())
+        }
+
+        match value {
+            None => Ok(self.push(name)),
+            Some(value) if is_token(value) => Ok(self.push(format!("{}={}", name, value))),
+            Some(value) => {
+                if value.chars().any(|c| c.is_ascii_control() && c != '\t') {
+                    return Err(invalid())
+                }
+                let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
+                Ok(self.push(format!("{}=\"{}\"", name, escaped)))
+            },
+        }
+    }
+
+    /// Retrieve the directives.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::cache_control;
+
+    #[test]
+    fn cache_control_rejects_control_values() {
+        assert!(cache_control().extension("foo", Some("a\r\nb")).is_err());
+
+        let welder = cache_control().extension("foo", Some(r#"a "b""#)).unwrap();
+        assert_eq!(r#"foo="a \"b\"""#, &welder.weld());
+    }
+}
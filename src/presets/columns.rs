@@ -0,0 +1,115 @@
+/// A welder of a long sequence of characters that inserts a newline
+/// every time a line reaches the given number of columns exactly.
+///
+/// Lines are never broken at word boundaries, the elements are split anywhere.
+///
+/// It is created by the `fixed_columns` function.
+pub struct FixedColumns {
+    welded: String,
+    columns: usize,
+    column: usize,
+    trailing_newline: bool,
+}
+
+/// Create a welder that breaks its output into lines of `columns` characters,
+/// the last line can be shorter.
+///
+/// # Panics
+///
+/// Panics if `columns` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::fixed_columns;
+///
+/// let welder = fixed_columns(4).elem("ACGTAC").elem("GTTA");
+///
+/// assert_eq!("ACGT\nACGT\nTA", &welder.weld());
+/// ```
+pub fn fixed_columns(columns: usize) -> FixedColumns {
+    assert!(columns != 0, "lines must contain at least one column");
+
+    FixedColumns {
+        welded: String::new(),
+        columns,
+        column: 0,
+        trailing_newline: false,
+    }
+}
+
+impl FixedColumns {
+    /// End the last line with a newline too, if the output is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::fixed_columns;
+    ///
+    /// let welder = fixed_columns(3).trailing_newline().elem("MKVLA");
+    ///
+    /// assert_eq!("MKV\nLA\n", &welder.weld());
+    /// ```
+    pub fn trailing_newline(mut self) -> Self {
+        self.trailing_newline = true;
+        self
+    }
+
+    /// Retrieve the lines.
+    pub fn weld(mut self) -> String {
+        if self.trailing_newline && !self.welded.is_empty() {
+            self.welded.push('\n');
+        }
+        self.welded
+    }
+
+    /// Append the characters of the element, breaking the line
+    /// every time it is full and more characters follow.
+    pub fn elem<E: AsRef<str>>(mut self, elem: E) -> Self {
+        for c in elem.as_ref().chars() {
+            if self.column == self.columns {
+                self.welded.push('\n');
+                self.column = 0;
+            }
+            self.welded.push(c);
+            self.column += 1;
+        }
+        self
+    }
+
+    /// Append the characters of each element, breaking the lines
+    /// every time they are full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::fixed_columns;
+    ///
+    /// let welder = fixed_columns(2).elems(vec!["a", "b", "c"]);
+    ///
+    /// assert_eq!("ab\nc", &welder.weld());
+    /// ```
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fixed_columns;
+
+    #[test]
+    fn fixed_columns_exact_multiple() {
+        let welder = fixed_columns(2).trailing_newline().elem("abcd");
+        assert_eq!("ab\ncd\n", &welder.weld());
+
+        assert_eq!("", &fixed_columns(2).trailing_newline().weld());
+    }
+}
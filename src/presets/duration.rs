@@ -0,0 +1,277 @@
+use std::time::Duration;
+
+use Welder;
+use super::join::join_elem;
+
+/// A unit of time a `HumanDuration` can be split into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeUnit {
+    /// Milliseconds, `ms`.
+    Millisecond,
+    /// Seconds, `s`.
+    Second,
+    /// Minutes, `m`.
+    Minute,
+    /// Hours, `h`.
+    Hour,
+    /// Days, `d`.
+    Day,
+}
+
+impl TimeUnit {
+    const ALL: [TimeUnit; 5] = [
+        TimeUnit::Day,
+        TimeUnit::Hour,
+        TimeUnit::Minute,
+        TimeUnit::Second,
+        TimeUnit::Millisecond,
+    ];
+
+    fn millis(self) -> u128 {
+        match self {
+            TimeUnit::Millisecond => 1,
+            TimeUnit::Second => 1000,
+            TimeUnit::Minute => 60 * 1000,
+            TimeUnit::Hour => 60 * 60 * 1000,
+            TimeUnit::Day => 24 * 60 * 60 * 1000,
+        }
+    }
+
+    fn short_name(self) -> &'static str {
+        match self {
+            TimeUnit::Millisecond => "ms",
+            TimeUnit::Second => "s",
+            TimeUnit::Minute => "m",
+            TimeUnit::Hour => "h",
+            TimeUnit::Day => "d",
+        }
+    }
+
+    fn long_name(self) -> &'static str {
+        match self {
+            TimeUnit::Millisecond => "millisecond",
+            TimeUnit::Second => "second",
+            TimeUnit::Minute => "minute",
+            TimeUnit::Hour => "hour",
+            TimeUnit::Day => "day",
+        }
+    }
+}
+
+/// A welder of the nonzero components of a `Duration`.
+///
+/// It is created by the `duration` function.
+pub struct HumanDuration {
+    duration: Duration,
+    separator: String,
+    largest: TimeUnit,
+    smallest: TimeUnit,
+    precision: usize,
+    long_names: bool,
+}
+
+/// Create a welder of the nonzero components of the duration,
+/// from days to seconds, separated by spaces.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use welder::presets::duration;
+///
+/// let welder = duration(Duration::from_secs(3723));
+///
+/// assert_eq!("1h 2m 3s", &welder.weld());
+/// ```
+pub fn duration(duration: Duration) -> HumanDuration {
+    HumanDuration {
+        duration,
+        separator: String::from(" "),
+        largest: TimeUnit::Day,
+        smallest: TimeUnit::Second,
+        precision: usize::MAX,
+        long_names: false,
+    }
+}
+
+impl HumanDuration {
+    /// Use the given separator between the components instead of a space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::duration;
+    ///
+    /// let welder = duration(Duration::from_secs(90)).separator(", ");
+    ///
+    /// assert_eq!("1m, 30s", &welder.weld());
+    /// ```
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_owned();
+        self
+    }
+
+    /// Do not split the duration in units larger than the given one,
+    /// the largest component accumulates the rest of the duration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::{duration, TimeUnit};
+    ///
+    /// let welder = duration(Duration::from_secs(2 * 86400 + 60)).largest(TimeUnit::Hour);
+    ///
+    /// assert_eq!("48h 1m", &welder.weld());
+    /// ```
+    pub fn largest(mut self, unit: TimeUnit) -> Self {
+        self.largest = unit;
+        self
+    }
+
+    /// Do not split the duration in units smaller than the given one,
+    /// the rest of the duration is truncated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::{duration, TimeUnit};
+    ///
+    /// let welder = duration(Duration::from_millis(1250)).smallest(TimeUnit::Millisecond);
+    ///
+    /// assert_eq!("1s 250ms", &welder.weld());
+    /// ```
+    pub fn smallest(mut self, unit: TimeUnit) -> Self {
+        self.smallest = unit;
+        self
+    }
+
+    /// Keep at most the given number of nonzero components, starting from the largest one,
+    /// the rest of the duration is truncated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `components` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::duration;
+    ///
+    /// let welder = duration(Duration::from_secs(93784)).precision(2);
+    ///
+    /// assert_eq!("1d 2h", &welder.weld());
+    /// ```
+    pub fn precision(mut self, components: usize) -> Self {
+        assert!(components != 0, "the precision must keep at least one component");
+        self.precision = components;
+        self
+    }
+
+    /// Spell the units out, `1 hour 2 minutes` instead of `1h 2m`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::duration;
+    ///
+    /// let welder = duration(Duration::from_secs(3660)).long_names();
+    ///
+    /// assert_eq!("1 hour 1 minute", &welder.weld());
+    /// ```
+    pub fn long_names(mut self) -> Self {
+        self.long_names = true;
+        self
+    }
+
+    /// Retrieve the components separated by the separator, a duration
+    /// without any nonzero component is written as zero of the smallest unit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::duration;
+    ///
+    /// assert_eq!("0s", &duration(Duration::from_millis(999)).weld());
+    /// ```
+    pub fn weld(self) -> String {
+        let (largest, smallest) = if self.largest < self.smallest {
+            (self.smallest, self.smallest)
+        } else {
+            (self.largest, self.smallest)
+        };
+
+        let mut rest = self.duration.as_millis();
+        let mut welder = Welder::new(self.separator.as_str());
+        let mut components = 0;
+
+        let units = TimeUnit::ALL.iter().filter(|&&u| u <= largest && u >= smallest);
+        for &unit in units {
+            if components == self.precision {
+                break
+            }
+
+            let count = rest / unit.millis();
+            rest %= unit.millis();
+            if count == 0 {
+                continue
+            }
+
+            let component = self.component(count, unit);
+            welder = join_elem(welder, component);
+            components += 1;
+        }
+
+        if components == 0 {
+            self.component(0, smallest)
+        } else {
+            welder.weld()
+        }
+    }
+
+    fn component(&self, count: u128, unit: TimeUnit) -> String {
+        if !self.long_names {
+            format!("{}{}", count, unit.short_name())
+        } else if count == 1 {
+            format!("{} {}", count, unit.long_name())
+        } else {
+            format!("{} {}s", count, unit.long_name())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{duration, TimeUnit};
+
+    #[test]
+    fn duration_skips_zero_components() {
+        let welder = duration(Duration::from_secs(86400 + 5));
+
+        assert_eq!("1d 5s", &welder.weld());
+    }
+
+    #[test]
+    fn duration_zero_long_names() {
+        let welder = duration(Duration::from_secs(0)).long_names().smallest(TimeUnit::Minute);
+
+        assert_eq!("0 minutes", &welder.weld());
+    }
+
+    #[test]
+    fn duration_precision_one() {
+        assert_eq!("1h", &duration(Duration::from_secs(3725)).precision(1).weld());
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one component")]
+    fn duration_precision_zero() {
+        duration(Duration::from_secs(3725)).precision(0);
+    }
+}
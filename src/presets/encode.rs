@@ -0,0 +1,18 @@
+/// Percent-encode every byte of the string except the unreserved characters
+/// of RFC 3986 and the extra characters given, hexadecimal digits are uppercase.
+pub fn percent_encode(out: &mut String, s: &str, keep: &str) {
+    for &byte in s.as_bytes() {
+        let c = byte as char;
+        if c.is_ascii_alphanumeric() || "-._~".contains(c) || (c.is_ascii() && keep.contains(c)) {
+            out.push(c);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+}
+
+/// Returns `true` if the string is a token of RFC 7230,
+/// a name that can appear unquoted in an HTTP header.
+pub fn is_token(s: &str) -> bool {
+    !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c))
+}
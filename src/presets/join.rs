@@ -0,0 +1,161 @@
+use {Glue, Weldable, Welder};
+
+/// A welder of `String`s that only puts its glue between the elements,
+/// never in front of the first one nor after the last one.
+///
+/// It is created by the `join` function and the `comma`, `comma_space`,
+/// `newline`, `space` and `pipe` shortcuts.
+pub struct Join<G> {
+    welder: Welder<G, String>,
+}
+
+/// Create a welder that joins its elements with the glue.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::join;
+///
+/// let welder = join(" -> ").elem("a").elem("b").elem("c");
+///
+/// assert_eq!("a -> b -> c", &welder.weld());
+/// ```
+pub fn join<G>(glue: G) -> Join<G> {
+    Join {
+        welder: Welder::new(glue),
+    }
+}
+
+/// Add the element to the welder, with a glue in front of it
+/// only if it is not the first one.
+pub fn join_elem<G, E>(welder: Welder<G, String>, elem: E) -> Welder<G, String>
+where
+    G: Glue<String>,
+    String: Weldable<E>,
+{
+    if welder.is_empty() {
+        welder.elem_no_glue(elem)
+    } else {
+        welder.elem(elem)
+    }
+}
+
+/// Create a welder that joins its elements with `','`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::comma;
+///
+/// assert_eq!("1,2,3", &comma().elems(vec!["1", "2", "3"]).weld());
+/// ```
+pub fn comma() -> Join<char> {
+    join(',')
+}
+
+/// Create a welder that joins its elements with `", "`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::comma_space;
+///
+/// assert_eq!("red, green, blue", &comma_space().elems(vec!["red", "green", "blue"]).weld());
+/// ```
+pub fn comma_space() -> Join<&'static str> {
+    join(", ")
+}
+
+/// Create a welder that joins its elements with `'\n'`, the last line
+/// is not followed by a newline.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::newline;
+///
+/// assert_eq!("first\nsecond", &newline().elem("first").elem("second").weld());
+/// ```
+pub fn newline() -> Join<char> {
+    join('\n')
+}
+
+/// Create a welder that joins its elements with `' '`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::space;
+///
+/// assert_eq!("ls -l -a", &space().elems(vec!["ls", "-l", "-a"]).weld());
+/// ```
+pub fn space() -> Join<char> {
+    join(' ')
+}
+
+/// Create a welder that joins its elements with `'|'`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::pipe;
+///
+/// assert_eq!("jpg|png|gif", &pipe().elems(vec!["jpg", "png", "gif"]).weld());
+/// ```
+pub fn pipe() -> Join<char> {
+    join('|')
+}
+
+impl<G> Join<G> {
+    /// Retrieve the `Welder`, the next elements are glued like with a `Welder`.
+    pub fn into_inner(self) -> Welder<G, String> {
+        self.welder
+    }
+
+    /// Retrieve the joined elements.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+}
+
+impl<G: Glue<String>> Join<G> {
+    /// Add the element, with a glue in front of it if it is not the first one.
+    pub fn elem<E>(mut self, elem: E) -> Self
+    where
+        String: Weldable<E>
+    {
+        self.welder = join_elem(self.welder, elem);
+        self
+    }
+
+    /// Add each element, with a glue in front of those that are not the first one.
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        String: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{comma, join};
+
+    #[test]
+    fn join_single_and_empty() {
+        assert_eq!("", &comma().weld());
+        assert_eq!("a", &comma().elem("a").weld());
+        assert_eq!(",", &comma().elem("").elem("").weld());
+    }
+
+    #[test]
+    fn join_into_inner_keeps_gluing() {
+        let welder = join(' ').elem("a").into_inner().elem("b");
+
+        assert_eq!("a b", &welder.weld());
+    }
+}
@@ -0,0 +1,76 @@
+use Welder;
+use super::base64::encode_url_safe;
+
+/// A welder of the compact serialization of a JSON Web Token,
+/// its base64url-encoded segments are separated by dots.
+///
+/// It is created by the `jwt` function.
+pub struct Jwt {
+    welder: Welder<char, String>,
+}
+
+fn encode<B: AsRef<[u8]>>(bytes: B) -> String {
+    let mut encoded = String::new();
+    encode_url_safe(&mut encoded, bytes.as_ref());
+    encoded
+}
+
+/// Create a token from the serialized JOSE header and payload.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::jwt;
+///
+/// let token = jwt(r#"{"alg":"none"}"#, r#"{"sub":"42"}"#);
+///
+/// assert_eq!("eyJhbGciOiJub25lIn0.eyJzdWIiOiI0MiJ9.", &token.weld_unsecured());
+/// ```
+pub fn jwt<H, P>(header: H, payload: P) -> Jwt
+where
+    H: AsRef<[u8]>,
+    P: AsRef<[u8]>,
+{
+    Jwt {
+        welder: Welder::with_start('.', encode(header)).elem(encode(payload)),
+    }
+}
+
+impl Jwt {
+    /// The encoded header and payload separated by a dot,
+    /// the input of the signature algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::jwt;
+    ///
+    /// let token = jwt(r#"{"alg":"HS256"}"#, "{}");
+    ///
+    /// assert_eq!("eyJhbGciOiJIUzI1NiJ9.e30", token.signing_input());
+    /// ```
+    pub fn signing_input(&self) -> &str {
+        self.welder.welded.as_str()
+    }
+
+    /// Retrieve the token, ending with the encoded signature bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::jwt;
+    ///
+    /// let token = jwt(r#"{"alg":"HS256"}"#, "{}");
+    ///
+    /// assert_eq!("eyJhbGciOiJIUzI1NiJ9.e30.AQL_", &token.weld(&[1, 2, 255]));
+    /// ```
+    pub fn weld<S: AsRef<[u8]>>(self, signature: S) -> String {
+        self.welder.elem(encode(signature)).weld()
+    }
+
+    /// Retrieve an unsecured token, the signature is empty
+    /// but the token still ends with a dot.
+    pub fn weld_unsecured(self) -> String {
+        self.welder.elem("").weld()
+    }
+}
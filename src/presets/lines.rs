@@ -0,0 +1,109 @@
+use std::fmt::{self, Write};
+
+use Welder;
+use super::join::join_elem;
+
+/// A welder that prefixes every element with its line number
+/// and puts every element on its own line.
+///
+/// It is created by the `numbered_lines` function.
+pub struct NumberedLines {
+    welder: Welder<char, String>,
+    width: usize,
+    next: usize,
+}
+
+/// Create a welder of numbered lines, the numbers are right-aligned
+/// and padded to the given width.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::numbered_lines;
+///
+/// let welder = numbered_lines(3).elem("fn main() {").elem("}");
+///
+/// assert_eq!("  1: fn main() {\n  2: }", &welder.weld());
+/// ```
+pub fn numbered_lines(width: usize) -> NumberedLines {
+    NumberedLines {
+        welder: Welder::new('\n'),
+        width,
+        next: 1,
+    }
+}
+
+impl NumberedLines {
+    /// Start numbering the lines from the given number instead of 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::numbered_lines;
+    ///
+    /// let welder = numbered_lines(2).starting_at(9);
+    ///
+    /// let welder = welder.elems(vec!["let x = 1;", "let y = x;"]);
+    ///
+    /// assert_eq!(" 9: let x = 1;\n10: let y = x;", &welder.weld());
+    /// ```
+    pub fn starting_at(mut self, number: usize) -> Self {
+        self.next = number;
+        self
+    }
+
+    /// Retrieve the numbered lines, the last one is not followed by a newline.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+
+    /// Add the element on a new line, prefixed by the next line number.
+    pub fn elem<E: fmt::Display>(mut self, elem: E) -> Self {
+        let mut line = String::new();
+        // writing into a String never fails
+        let _ = write!(line, "{:>width$}: {}", self.next, elem, width = self.width);
+
+        self.welder = join_elem(self.welder, line);
+        self.next += 1;
+        self
+    }
+
+    /// Add each element on its own line, prefixed by the next line numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::numbered_lines;
+    ///
+    /// let welder = numbered_lines(1).elems(1..4);
+    ///
+    /// assert_eq!("1: 1\n2: 2\n3: 3", &welder.weld());
+    /// ```
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: fmt::Display,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::numbered_lines;
+
+    #[test]
+    fn numbered_lines_empty() {
+        assert_eq!("", &numbered_lines(3).weld());
+    }
+
+    #[test]
+    fn numbered_lines_wider_than_width() {
+        let welder = numbered_lines(1).starting_at(99).elems(vec!["a", "b"]);
+
+        assert_eq!("99: a\n100: b", &welder.weld());
+    }
+}
@@ -0,0 +1,206 @@
+use std::error::Error;
+use std::fmt;
+
+use Welder;
+use super::join::join_elem;
+use super::encode::is_token;
+
+/// The error returned when the name of a `Link` parameter is not a token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidParam {
+    /// The name of the rejected parameter.
+    pub name: String,
+}
+
+impl fmt::Display for InvalidParam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid Link parameter name {:?}", self.name)
+    }
+}
+
+impl Error for InvalidParam {}
+
+/// A single link of a `Link` header, its target and its parameters
+/// separated by `"; "`.
+///
+/// It is created by the `link` function.
+pub struct Link {
+    welder: Welder<&'static str, String>,
+}
+
+/// A welder of links separated by `", "`, as found in the `Link` header of RFC 8288.
+///
+/// It is created by the `link_header` function.
+pub struct LinkHeader {
+    welder: Welder<&'static str, String>,
+}
+
+/// Create a link to the given target.
+///
+/// Characters that cannot appear between the angle brackets,
+/// like spaces and angle brackets, are percent-encoded.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::link;
+///
+/// let link = link("https://example.com/items?page=2").rel("next");
+///
+/// assert_eq!(r#"<https://example.com/items?page=2>; rel="next""#, &link.weld());
+/// ```
+pub fn link<U: AsRef<str>>(target: U) -> Link {
+    let mut uri = String::from("<");
+    for c in target.as_ref().chars() {
+        if c == '<' || c == '>' || c == '"' || c.is_whitespace() || c.is_control() {
+            let mut bytes = [0; 4];
+            for byte in c.encode_utf8(&mut bytes).bytes() {
+                uri.push_str(&format!("%{:02X}", byte));
+            }
+        } else {
+            uri.push(c);
+        }
+    }
+    uri.push('>');
+
+    Link {
+        welder: Welder::with_start("; ", uri),
+    }
+}
+
+/// Create an empty `Link` header.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::{link, link_header};
+///
+/// let welder = link_header()
+///     .link(link("/items?page=1").rel("prev"))
+///     .link(link("/items?page=3").rel("next").title("Next page"));
+///
+/// let expected = r#"</items?page=1>; rel="prev", </items?page=3>; rel="next"; title="Next page""#;
+/// assert_eq!(expected, &welder.weld());
+/// ```
+pub fn link_header() -> LinkHeader {
+    LinkHeader {
+        welder: Welder::new(", "),
+    }
+}
+
+impl Link {
+    /// Add a parameter, the name must be a token, the value is always quoted
+    /// and quotes and backslashes in it are escaped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::link;
+    ///
+    /// let home = link("/").param("title", r#"The "home" page"#).unwrap();
+    /// assert_eq!(r#"</>; title="The \"home\" page""#, &home.weld());
+    ///
+    /// assert!(link("/").param("rel=\"x\"; a", "b").is_err());
+    /// ```
+    pub fn param<N, V>(self, name: N, value: V) -> Result<Self, InvalidParam>
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let name = name.as_ref();
+        if !is_token(name) {
+            return Err(InvalidParam { name: name.to_owned() })
+        }
+        Ok(self.push_param(name, value.as_ref()))
+    }
+
+    fn push_param(mut self, name: &str, value: &str) -> Self {
+        let mut param = String::from(name);
+        param.push_str("=\"");
+        for c in value.chars().filter(|&c| c != '\r' && c != '\n') {
+            if c == '"' || c == '\\' {
+                param.push('\\');
+            }
+            param.push(c);
+        }
+        param.push('"');
+
+        self.welder = self.welder.elem(param);
+        self
+    }
+
+    /// Add the `rel` parameter.
+    pub fn rel<V: AsRef<str>>(self, relation: V) -> Self {
+        self.push_param("rel", relation.as_ref())
+    }
+
+    /// Add the `title` parameter.
+    pub fn title<V: AsRef<str>>(self, title: V) -> Self {
+        self.push_param("title", title.as_ref())
+    }
+
+    /// Add the `type` parameter.
+    pub fn media_type<V: AsRef<str>>(self, media_type: V) -> Self {
+        self.push_param("type", media_type.as_ref())
+    }
+
+    /// Retrieve the link and its parameters.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+}
+
+impl LinkHeader {
+    /// Add a link to the header.
+    pub fn link(mut self, link: Link) -> Self {
+        let link = link.weld();
+        self.welder = join_elem(self.welder, link);
+        self
+    }
+
+    /// Add each link to the header.
+    pub fn links<I>(mut self, links: I) -> Self
+    where
+        I: IntoIterator<Item = Link>,
+    {
+        for link in links {
+            self = self.link(link)
+        }
+        self
+    }
+
+    /// Retrieve the value of the `Link` header.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{link, link_header};
+
+    #[test]
+    fn link_encodes_target() {
+        let link = link("/a b/<c>/é");
+
+        assert_eq!("</a%20b/%3Cc%3E/é>", &link.weld());
+    }
+
+    #[test]
+    fn link_header_links() {
+        let links = vec![link("/1"), link("/2").rel("last")];
+
+        assert_eq!(r#"</1>, </2>; rel="last""#, &link_header().links(links).weld());
+        assert_eq!("", &link_header().weld());
+    }
+
+    #[test]
+    fn link_param_names_are_tokens() {
+        for name in &["", "a;b", "a=b", "a\"b", "a\r\nb", "a b"] {
+            assert!(link("/").param(name, "v").is_err());
+        }
+
+        let link = link("/").param("hreflang", "fr").unwrap();
+        assert_eq!(r#"</>; hreflang="fr""#, &link.weld());
+    }
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use Welder;
+
+/// A welder of M3U playlist entries, each entry is an `#EXTINF` line
+/// followed by the location of the media.
+///
+/// It is created by the `m3u` function.
+pub struct Playlist {
+    welder: Welder<char, String>,
+}
+
+/// Create an empty M3U playlist, the `#EXTM3U` header is written when it is welded.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use welder::presets::m3u;
+///
+/// let welder = m3u().entry(Some(Duration::from_secs(215)), "Artist - Title", "song.mp3");
+///
+/// assert_eq!("#EXTM3U\n#EXTINF:215,Artist - Title\nsong.mp3\n", &welder.weld());
+/// ```
+pub fn m3u() -> Playlist {
+    Playlist {
+        welder: Welder::new('\n'),
+    }
+}
+
+/// Format a duration in seconds, without trailing zeros and with at most millisecond precision.
+fn format_seconds(duration: Duration) -> String {
+    let millis = duration.subsec_millis();
+    if millis == 0 {
+        return duration.as_secs().to_string()
+    }
+
+    let string = format!("{}.{:03}", duration.as_secs(), millis);
+    string.trim_end_matches('0').to_owned()
+}
+
+fn single_line(s: &str) -> String {
+    s.replace(['\r', '\n'], " ")
+}
+
+impl Playlist {
+    /// Add an entry to the playlist, the duration is unknown when it is `None`.
+    ///
+    /// Line breaks in the title and in the location are replaced by spaces
+    /// to keep each of them on its line, they cannot inject other lines.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use welder::presets::m3u;
+    ///
+    /// let welder = m3u()
+    ///     .entry(Some(Duration::from_millis(9_009)), "Segment 1", "seg1.ts")
+    ///     .entry(None, "Live", "http://example.com/live");
+    ///
+    /// let expected = "#EXTM3U\n\
+    ///                 #EXTINF:9.009,Segment 1\nseg1.ts\n\
+    ///                 #EXTINF:-1,Live\nhttp://example.com/live\n";
+    /// assert_eq!(expected, &welder.weld());
+    /// ```
+    pub fn entry<T, U>(mut self, duration: Option<Duration>, title: T, location: U) -> Self
+    where
+        T: AsRef<str>,
+        U: AsRef<str>,
+    {
+        let duration = duration.map_or_else(|| String::from("-1"), format_seconds);
+        let title = single_line(title.as_ref());
+        let extinf = format!("#EXTINF:{},{}", duration, title);
+
+        self.welder = self.welder
+                          .elem_glue_right(extinf)
+                          .elem_glue_right(single_line(location.as_ref()));
+        self
+    }
+
+    /// Retrieve the playlist, starting with the `#EXTM3U` header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::m3u;
+    ///
+    /// assert_eq!("#EXTM3U\n", &m3u().weld());
+    /// ```
+    pub fn weld(self) -> String {
+        let mut playlist = String::from("#EXTM3U\n");
+        playlist.push_str(&self.welder.weld());
+        playlist
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{format_seconds, m3u};
+
+    #[test]
+    fn m3u_seconds_trim_zeros() {
+        assert_eq!("10.5", &format_seconds(Duration::from_millis(10_500)));
+        assert_eq!("0.001", &format_seconds(Duration::from_micros(1_999)));
+        assert_eq!("7", &format_seconds(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn m3u_location_cannot_inject_lines() {
+        let welder = m3u().entry(None, "Title", "a.mp3\n#EXTINF:1,Injected\r\nevil.mp3");
+
+        let playlist = welder.weld();
+        assert_eq!(3, playlist.lines().count());
+        assert_eq!(Some("a.mp3 #EXTINF:1,Injected  evil.mp3"), playlist.lines().nth(2));
+    }
+}
@@ -0,0 +1,37 @@
+//! Ready-made welders for common output formats.
+
+mod address;
+mod base64;
+mod breadcrumb;
+mod cache_control;
+mod columns;
+mod duration;
+mod encode;
+mod join;
+mod jwt;
+mod lines;
+mod link;
+mod m3u;
+mod pem;
+mod query;
+mod sentence;
+mod sigv4;
+mod subtitles;
+mod user_agent;
+
+pub use self::address::{address_list, AddressList, InvalidAddress};
+pub use self::breadcrumb::{breadcrumb, Breadcrumb};
+pub use self::cache_control::{cache_control, CacheControl, InvalidDirective};
+pub use self::columns::{fixed_columns, FixedColumns};
+pub use self::duration::{duration, HumanDuration, TimeUnit};
+pub use self::join::{comma, comma_space, join, newline, pipe, space, Join};
+pub use self::jwt::{jwt, Jwt};
+pub use self::lines::{numbered_lines, NumberedLines};
+pub use self::link::{link, link_header, InvalidParam, Link, LinkHeader};
+pub use self::m3u::{m3u, Playlist};
+pub use self::pem::{pem, Pem};
+pub use self::query::{sorted_query, SortedQuery};
+pub use self::sentence::{sentence, Sentence};
+pub use self::sigv4::{canonical_request, CanonicalRequest};
+pub use self::subtitles::{srt, webvtt, Subtitles};
+pub use self::user_agent::{user_agent, UserAgent};
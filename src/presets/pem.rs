@@ -0,0 +1,74 @@
+use super::base64::encode_standard;
+use super::columns::fixed_columns;
+
+/// A welder of bytes armored as PEM, base64-encoded lines of 64 characters
+/// between the `BEGIN` and the `END` lines of the label.
+///
+/// It is created by the `pem` function.
+pub struct Pem {
+    label: String,
+    bytes: Vec<u8>,
+}
+
+/// Create an empty PEM document with the given label, like `CERTIFICATE`.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::pem;
+///
+/// let welder = pem("PUBLIC KEY").bytes(b"hello");
+///
+/// assert_eq!("-----BEGIN PUBLIC KEY-----\naGVsbG8=\n-----END PUBLIC KEY-----\n", &welder.weld());
+/// ```
+pub fn pem<L: AsRef<str>>(label: L) -> Pem {
+    Pem {
+        label: label.as_ref().to_owned(),
+        bytes: Vec::new(),
+    }
+}
+
+impl Pem {
+    /// Append the bytes to the armored content.
+    pub fn bytes<B: AsRef<[u8]>>(mut self, bytes: B) -> Self {
+        self.bytes.extend_from_slice(bytes.as_ref());
+        self
+    }
+
+    /// Retrieve the PEM document, it ends with a newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::pem;
+    ///
+    /// let welder = pem("DATA").bytes(vec![0; 60]);
+    ///
+    /// let pem = welder.weld();
+    /// let lines: Vec<_> = pem.lines().collect();
+    ///
+    /// assert_eq!("-----BEGIN DATA-----", lines[0]);
+    /// assert_eq!(64, lines[1].len());
+    /// assert_eq!("AAAAAAAAAAAAAAAA", lines[2]);
+    /// assert_eq!("-----END DATA-----", lines[3]);
+    /// ```
+    pub fn weld(self) -> String {
+        let mut encoded = String::new();
+        encode_standard(&mut encoded, &self.bytes);
+
+        let mut pem = format!("-----BEGIN {}-----\n", self.label);
+        pem.push_str(&fixed_columns(64).trailing_newline().elem(encoded).weld());
+        pem.push_str(&format!("-----END {}-----\n", self.label));
+        pem
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pem;
+
+    #[test]
+    fn pem_empty_content() {
+        assert_eq!("-----BEGIN X-----\n-----END X-----\n", &pem("X").weld());
+    }
+}
@@ -0,0 +1,92 @@
+use Welder;
+use super::encode::percent_encode;
+
+/// A welder of query-string parameters that sorts them, bytewise after encoding,
+/// instead of keeping the order they were added in.
+///
+/// The same parameters always produce the same query string, as required
+/// to sign requests or to normalize cache keys.
+///
+/// It is created by the `sorted_query` function.
+pub struct SortedQuery {
+    pairs: Vec<(String, String)>,
+}
+
+/// Create an empty sorted query string.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::sorted_query;
+///
+/// let welder = sorted_query().pair("b", "2").pair("a", "hello world").pair("B", "3");
+///
+/// assert_eq!("B=3&a=hello%20world&b=2", &welder.weld());
+/// ```
+pub fn sorted_query() -> SortedQuery {
+    SortedQuery { pairs: Vec::new() }
+}
+
+impl SortedQuery {
+    /// Add a parameter, the name and the value are percent-encoded,
+    /// only the unreserved characters are kept as is.
+    pub fn pair<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut encoded_name = String::new();
+        let mut encoded_value = String::new();
+        percent_encode(&mut encoded_name, name.as_ref(), "");
+        percent_encode(&mut encoded_value, value.as_ref(), "");
+        self.pairs.push((encoded_name, encoded_value));
+        self
+    }
+
+    /// Add each parameter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::sorted_query;
+    ///
+    /// let welder = sorted_query().pairs(vec![("tag", "b"), ("tag", "a"), ("q", "x&y")]);
+    ///
+    /// assert_eq!("q=x%26y&tag=a&tag=b", &welder.weld());
+    /// ```
+    pub fn pairs<I, K, V>(mut self, pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for (name, value) in pairs {
+            self = self.pair(name, value)
+        }
+        self
+    }
+
+    /// Retrieve the parameters sorted by name then by value, joined by `&`.
+    pub fn weld(mut self) -> String {
+        self.pairs.sort();
+
+        let pairs = self.pairs.into_iter().map(|(name, value)| {
+            Welder::<_, String>::with_start('=', name).elem(value).weld()
+        });
+        Welder::from_iter('&', pairs).weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sorted_query;
+
+    #[test]
+    fn sorted_query_sorts_after_encoding() {
+        // '%' sorts before the letters, the encoded space comes first
+        let welder = sorted_query().pair("a", "b").pair(" ", "c");
+
+        assert_eq!("%20=c&a=b", &welder.weld());
+        assert_eq!("", &sorted_query().weld());
+    }
+}
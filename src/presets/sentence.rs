@@ -0,0 +1,101 @@
+use super::join::{space, Join};
+
+/// A welder of clauses separated by spaces, the output starts with
+/// an uppercase character and ends with a punctuation mark.
+///
+/// It is created by the `sentence` function.
+pub struct Sentence {
+    join: Join<char>,
+    terminator: char,
+}
+
+/// Create an empty sentence ending with a period.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::sentence;
+///
+/// let welder = sentence().elem("3 files").elem("were copied");
+///
+/// assert_eq!("3 files were copied.", &welder.weld());
+///
+/// let welder = sentence().elems(vec!["the build", "failed"]);
+///
+/// assert_eq!("The build failed.", &welder.weld());
+/// ```
+pub fn sentence() -> Sentence {
+    Sentence {
+        join: space(),
+        terminator: '.',
+    }
+}
+
+impl Sentence {
+    /// End the sentence with the given punctuation mark instead of a period.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::sentence;
+    ///
+    /// let welder = sentence().terminator('!').elem("done");
+    ///
+    /// assert_eq!("Done!", &welder.weld());
+    /// ```
+    pub fn terminator(mut self, terminator: char) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Add a clause, empty clauses are ignored.
+    pub fn elem<E: AsRef<str>>(mut self, clause: E) -> Self {
+        let clause = clause.as_ref();
+        if !clause.is_empty() {
+            self.join = self.join.elem(clause);
+        }
+        self
+    }
+
+    /// Add each clause, empty clauses are ignored.
+    pub fn elems<I>(mut self, clauses: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for clause in clauses {
+            self = self.elem(clause)
+        }
+        self
+    }
+
+    /// Retrieve the sentence, the punctuation mark is not added again
+    /// if the last clause already ends with it and an empty sentence stays empty.
+    pub fn weld(self) -> String {
+        let welded = self.join.weld();
+        let mut chars = welded.chars();
+        let first = match chars.next() {
+            Some(first) => first,
+            None => return welded,
+        };
+
+        let mut sentence: String = first.to_uppercase().collect();
+        sentence.push_str(chars.as_str());
+        if !sentence.ends_with(self.terminator) {
+            sentence.push(self.terminator);
+        }
+        sentence
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sentence;
+
+    #[test]
+    fn sentence_edge_cases() {
+        assert_eq!("", &sentence().elem("").weld());
+        assert_eq!("Éclair.", &sentence().elem("éclair").weld());
+        assert_eq!("Really?", &sentence().terminator('?').elem("really?").weld());
+    }
+}
@@ -0,0 +1,135 @@
+use Welder;
+use super::encode::percent_encode;
+use super::query::{sorted_query, SortedQuery};
+
+/// A welder of the canonical request of the AWS Signature Version 4,
+/// its components are separated by newlines.
+///
+/// It is created by the `canonical_request` function.
+pub struct CanonicalRequest {
+    method: String,
+    path: String,
+    query: SortedQuery,
+    headers: Vec<(String, String)>,
+}
+
+/// Create the canonical request of the given HTTP method and absolute path.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::canonical_request;
+///
+/// let request = canonical_request("GET", "/test.txt")
+///     .query("list-type", "2")
+///     .header("Host", "examplebucket.s3.amazonaws.com")
+///     .header("X-Amz-Date", "20130524T000000Z");
+///
+/// let payload_hash = "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+/// let expected = "GET\n\
+///                 /test.txt\n\
+///                 list-type=2\n\
+///                 host:examplebucket.s3.amazonaws.com\n\
+///                 x-amz-date:20130524T000000Z\n\
+///                 \n\
+///                 host;x-amz-date\n\
+///                 e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+/// assert_eq!(expected, &request.weld(payload_hash));
+/// ```
+pub fn canonical_request<M, P>(method: M, path: P) -> CanonicalRequest
+where
+    M: AsRef<str>,
+    P: AsRef<str>,
+{
+    CanonicalRequest {
+        method: method.as_ref().to_owned(),
+        path: path.as_ref().to_owned(),
+        query: sorted_query(),
+        headers: Vec::new(),
+    }
+}
+
+impl CanonicalRequest {
+    /// Add a query parameter, the parameters are encoded then sorted by name and value.
+    pub fn query<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        self.query = self.query.pair(name, value);
+        self
+    }
+
+    /// Add a header to sign, the name is lowercased and the sequences of spaces
+    /// of the value are collapsed, the values of the headers with the same name
+    /// are separated by commas in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::canonical_request;
+    ///
+    /// let request = canonical_request("GET", "/")
+    ///     .header("My-Header", "  a   b  ")
+    ///     .header("my-header", "c");
+    ///
+    /// assert_eq!("GET\n/\n\nmy-header:a b,c\n\nmy-header\n-", &request.weld("-"));
+    /// ```
+    pub fn header<N, V>(mut self, name: N, value: V) -> Self
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let name = name.as_ref().trim().to_ascii_lowercase();
+        let value: Vec<_> = value.as_ref().split_whitespace().collect();
+        let value = value.join(" ");
+
+        match self.headers.iter_mut().find(|&&mut (ref n, _)| *n == name) {
+            Some(&mut (_, ref mut values)) => {
+                values.push(',');
+                values.push_str(&value);
+            },
+            None => self.headers.push((name, value)),
+        }
+        self
+    }
+
+    /// Retrieve the canonical request, ending with the given hexadecimal hash of the payload.
+    ///
+    /// The segments of the path are encoded, an empty path is written as `/`.
+    pub fn weld(mut self, payload_hash: &str) -> String {
+        let mut path = String::new();
+        percent_encode(&mut path, &self.path, "/");
+        if path.is_empty() {
+            path.push('/');
+        }
+
+        self.headers.sort_by(|a, b| a.0.cmp(&b.0));
+        let headers = self.headers.iter().map(|(name, value)| format!("{}:{}\n", name, value));
+        let signed = self.headers.iter().map(|(name, _)| name.as_str());
+
+        let welder = Welder::with_start('\n', self.method)
+                        .elem(path)
+                        .elem(self.query.weld())
+                        .elem(Welder::<_, String>::from_iter("", headers).weld())
+                        .elem(Welder::<_, String>::from_iter(';', signed).weld())
+                        .elem(payload_hash);
+        welder.weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::canonical_request;
+
+    #[test]
+    fn canonical_request_encodes_and_sorts_query() {
+        let request = canonical_request("GET", "/a b/c")
+            .query("b", "x y")
+            .query("a", "2")
+            .query("a", "1");
+
+        let expected = "GET\n/a%20b/c\na=1&a=2&b=x%20y\n\n\nh";
+        assert_eq!(expected, &request.weld("h"));
+    }
+}
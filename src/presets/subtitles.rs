@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use Welder;
+use super::join::join_elem;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Srt,
+    WebVtt,
+}
+
+/// A welder of subtitle cue blocks separated by blank lines.
+///
+/// It is created by the `srt` or the `webvtt` functions.
+pub struct Subtitles {
+    welder: Welder<&'static str, String>,
+    format: Format,
+    next: usize,
+}
+
+/// Create an empty SubRip subtitle file, cues are numbered from 1
+/// and timestamps are written as `HH:MM:SS,mmm`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use welder::presets::srt;
+///
+/// let welder = srt()
+///     .cue(Duration::from_millis(1_000), Duration::from_millis(2_500), "Hello!")
+///     .cue(Duration::from_secs(3), Duration::from_secs(5), "How are you?\nFine.");
+///
+/// let expected = "1\n00:00:01,000 --> 00:00:02,500\nHello!\n\n\
+///                 2\n00:00:03,000 --> 00:00:05,000\nHow are you?\nFine.\n";
+/// assert_eq!(expected, &welder.weld());
+/// ```
+pub fn srt() -> Subtitles {
+    Subtitles {
+        welder: Welder::new("\n\n"),
+        format: Format::Srt,
+        next: 1,
+    }
+}
+
+/// Create an empty WebVTT subtitle file, the `WEBVTT` header is written
+/// when it is welded and timestamps are written as `HH:MM:SS.mmm`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use welder::presets::webvtt;
+///
+/// let welder = webvtt().cue(Duration::from_millis(500), Duration::from_secs(2), "Hello!");
+///
+/// assert_eq!("WEBVTT\n\n00:00:00.500 --> 00:00:02.000\nHello!\n", &welder.weld());
+/// ```
+pub fn webvtt() -> Subtitles {
+    Subtitles {
+        welder: Welder::new("\n\n"),
+        format: Format::WebVtt,
+        next: 1,
+    }
+}
+
+fn format_timestamp(time: Duration, separator: char) -> String {
+    let secs = time.as_secs();
+    format!("{:02}:{:02}:{:02}{}{:03}",
+        secs / 3600, secs / 60 % 60, secs % 60, separator, time.subsec_millis())
+}
+
+impl Subtitles {
+    /// Add a cue displaying the text between the two timestamps.
+    ///
+    /// Blank lines would end the cue block early, they are removed from the text.
+    pub fn cue<T: AsRef<str>>(mut self, start: Duration, end: Duration, text: T) -> Self {
+        let (index, separator) = match self.format {
+            Format::Srt => (Some(self.next), ','),
+            Format::WebVtt => (None, '.'),
+        };
+
+        let timing = format!("{} --> {}",
+            format_timestamp(start, separator), format_timestamp(end, separator));
+        let block = match index {
+            Some(index) => Welder::with_start('\n', index.to_string()).elem(timing),
+            None => Welder::with_start('\n', timing),
+        };
+        let lines = text.as_ref().lines().filter(|line| !line.trim().is_empty());
+        let block: String = block.elems(lines).weld();
+
+        self.welder = join_elem(self.welder, block.as_str());
+        self.next += 1;
+        self
+    }
+
+    /// Retrieve the subtitles, the last cue is followed by a newline.
+    pub fn weld(self) -> String {
+        let mut subtitles = match self.format {
+            Format::Srt => String::new(),
+            Format::WebVtt => String::from("WEBVTT\n\n"),
+        };
+        if !self.welder.is_empty() {
+            subtitles.push_str(&self.welder.weld());
+            subtitles.push('\n');
+        }
+        subtitles
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use super::{format_timestamp, srt};
+
+    #[test]
+    fn subtitles_long_timestamps() {
+        let time = Duration::from_millis(((100 * 60 + 2) * 60 + 3) * 1000 + 4);
+
+        assert_eq!("100:02:03,004", &format_timestamp(time, ','));
+    }
+
+    #[test]
+    fn subtitles_remove_blank_lines() {
+        let welder = srt().cue(Duration::from_secs(0), Duration::from_secs(1), "a\n\nb\n");
+
+        assert_eq!("1\n00:00:00,000 --> 00:00:01,000\na\nb\n", &welder.weld());
+        assert_eq!("", &srt().weld());
+    }
+}
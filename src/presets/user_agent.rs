@@ -0,0 +1,128 @@
+use Welder;
+use super::join::join_elem;
+
+/// A welder of `User-Agent` products and comments separated by spaces.
+///
+/// It is created by the `user_agent` function.
+pub struct UserAgent {
+    welder: Welder<char, String>,
+}
+
+/// Create an empty `User-Agent` string.
+///
+/// # Examples
+///
+/// ```
+/// use welder::presets::user_agent;
+///
+/// let welder = user_agent()
+///     .product("Mozilla", "5.0")
+///     .comment(vec!["X11", "Linux x86_64"])
+///     .product("Gecko", "20100101");
+///
+/// assert_eq!("Mozilla/5.0 (X11; Linux x86_64) Gecko/20100101", &welder.weld());
+/// ```
+pub fn user_agent() -> UserAgent {
+    UserAgent {
+        welder: Welder::new(' '),
+    }
+}
+
+/// Returns `true` if the character is allowed in an HTTP token.
+fn is_tchar(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+-.^_`|~".contains(c)
+}
+
+/// Replace the characters that are not allowed in an HTTP token by dashes.
+fn push_token(out: &mut String, token: &str) {
+    out.extend(token.chars().map(|c| if is_tchar(c) { c } else { '-' }));
+}
+
+impl UserAgent {
+    fn push(mut self, part: String) -> Self {
+        self.welder = join_elem(self.welder, part);
+        self
+    }
+
+    /// Add a product token with its version, written as `name/version`.
+    ///
+    /// Characters that are not allowed in a token are replaced by dashes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::user_agent;
+    ///
+    /// let welder = user_agent().product("my client", "1.2 beta");
+    ///
+    /// assert_eq!("my-client/1.2-beta", &welder.weld());
+    /// ```
+    pub fn product<N, V>(self, name: N, version: V) -> Self
+    where
+        N: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut product = String::new();
+        push_token(&mut product, name.as_ref());
+        product.push('/');
+        push_token(&mut product, version.as_ref());
+        self.push(product)
+    }
+
+    /// Add a product token without any version.
+    pub fn product_name<N: AsRef<str>>(self, name: N) -> Self {
+        let mut product = String::new();
+        push_token(&mut product, name.as_ref());
+        self.push(product)
+    }
+
+    /// Add a comment made of the segments separated by `"; "` and enclosed in parentheses.
+    ///
+    /// Parentheses and backslashes in the segments are escaped with a backslash.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::presets::user_agent;
+    ///
+    /// let welder = user_agent().product_name("bot").comment(vec!["+https://example.com (info)"]);
+    ///
+    /// assert_eq!(r"bot (+https://example.com \(info\))", &welder.weld());
+    /// ```
+    pub fn comment<I>(self, segments: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let segments = segments.into_iter().map(|segment| {
+            let mut escaped = String::new();
+            for c in segment.as_ref().chars().filter(|&c| c != '\r' && c != '\n') {
+                if c == '(' || c == ')' || c == '\\' {
+                    escaped.push('\\');
+                }
+                escaped.push(c);
+            }
+            escaped
+        });
+
+        let comment: String = Welder::from_iter("; ", segments).weld();
+        self.push(format!("({})", comment))
+    }
+
+    /// Retrieve the `User-Agent` string.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::user_agent;
+
+    #[test]
+    fn user_agent_empty_comment() {
+        let welder = user_agent().product_name("curl").comment(Vec::<&str>::new());
+
+        assert_eq!("curl ()", &welder.weld());
+    }
+}
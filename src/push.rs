@@ -0,0 +1,332 @@
+use {Glue, Weldable, Welder};
+
+impl<G, T> Welder<G, T> {
+    /// Add the element without any glue, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    ///
+    /// welder.push_elem_no_glue("bar").push_elem_no_glue("baz");
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("foobarbaz", &string);
+    /// ```
+    pub fn push_elem_no_glue<E>(&mut self, elem: E) -> &mut Self
+    where
+        T: Weldable<E>
+    {
+        self.welded.weld_elem(elem);
+        self.count_elems(1);
+        self
+    }
+
+    /// Add each element without any glue, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    ///
+    /// welder.push_elems_no_glue(vec!["bar", "baz"]);
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("foobarbaz", &string);
+    /// ```
+    pub fn push_elems_no_glue<I>(&mut self, elems: I) -> &mut Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        let mut count = 0;
+        self.welded.weld_elems(elems.into_iter().inspect(|_| count += 1));
+        self.count_elems(count);
+        self
+    }
+
+    /// Add the element with the given glue in front of it, instead of
+    /// the glue of the `Welder`, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(", ", "fast");
+    ///
+    /// welder.push_elem_with_glue(" and ", "cheap");
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("fast and cheap", &string);
+    /// ```
+    pub fn push_elem_with_glue<H, E>(&mut self, mut glue: H, elem: E) -> &mut Self
+    where
+        H: Glue<T>,
+        T: Weldable<E>,
+    {
+        glue.apply(&mut self.welded);
+        self.welded.weld_elem(elem);
+        self.glues += 1;
+        self.count_elems(1);
+        self
+    }
+
+    /// Add each element with the given glue in front of it, instead of
+    /// the glue of the `Welder`, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(", ", "a");
+    ///
+    /// welder.push_elems_with_glue(" | ", vec!["b", "c"]);
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("a | b | c", &string);
+    /// ```
+    pub fn push_elems_with_glue<H, I>(&mut self, mut glue: H, elems: I) -> &mut Self
+    where
+        H: Glue<T>,
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            glue.apply(&mut self.welded);
+            self.welded.weld_elem(elem);
+            self.glues += 1;
+            self.count_elems(1);
+        }
+        self
+    }
+}
+
+impl<G, T> Welder<G, T>
+where
+    G: Glue<T>,
+{
+    /// Add the element with a glue in front of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::with_start(", ", "foo");
+    ///
+    /// for i in 0..3 {
+    ///     if i % 2 == 0 {
+    ///         welder.push_elem(i.to_string());
+    ///     }
+    /// }
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("foo, 0, 2", &string);
+    /// ```
+    pub fn push_elem<E>(&mut self, elem: E) -> &mut Self
+    where
+        T: Weldable<E>
+    {
+        self.push_elem_glue_left(elem)
+    }
+
+    /// Add each element with a glue in front of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::new(' ');
+    ///
+    /// welder.push_elems(vec!["foo", "bar"]).push_elem("baz");
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!(" foo bar baz", &string);
+    /// ```
+    pub fn push_elems<I>(&mut self, elems: I) -> &mut Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        self.push_elems_glue_left(elems)
+    }
+
+    /// Add the element with a glue to the right of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::new('\n');
+    ///
+    /// welder.push_elem_glue_right("foo").push_elem_glue_right("bar");
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("foo\nbar\n", &string);
+    /// ```
+    pub fn push_elem_glue_right<E>(&mut self, elem: E) -> &mut Self
+    where
+        T: Weldable<E>
+    {
+        self.welded.weld_elem(elem);
+        self.glue.apply(&mut self.welded);
+        self.glues += 1;
+        self.count_elems(1);
+        self
+    }
+
+    /// Add each element with a glue to the right of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::new(';');
+    ///
+    /// welder.push_elems_glue_right(vec!["a = 1", "b = 2"]);
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("a = 1;b = 2;", &string);
+    /// ```
+    pub fn push_elems_glue_right<I>(&mut self, elems: I) -> &mut Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self.push_elem_glue_right(elem);
+        }
+        self
+    }
+
+    /// Add the element with a glue to the left of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, Vec<_>> = Welder::new(0);
+    ///
+    /// welder.push_elem_glue_left(12);
+    ///
+    /// let vec = welder.weld();
+    /// assert_eq!(&[0, 12], vec.as_slice());
+    /// ```
+    pub fn push_elem_glue_left<E>(&mut self, elem: E) -> &mut Self
+    where
+        T: Weldable<E>
+    {
+        self.glue.apply(&mut self.welded);
+        self.welded.weld_elem(elem);
+        self.glues += 1;
+        self.count_elems(1);
+        self
+    }
+
+    /// Add each element with a glue to the left of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, Vec<_>> = Welder::new(0);
+    ///
+    /// welder.push_elems_glue_left(vec![12, 14]);
+    ///
+    /// let vec = welder.weld();
+    /// assert_eq!(&[0, 12, 0, 14], vec.as_slice());
+    /// ```
+    pub fn push_elems_glue_left<I>(&mut self, elems: I) -> &mut Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self.push_elem_glue_left(elem);
+        }
+        self
+    }
+
+    /// Add the element with a glue on both sides of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::new('|');
+    ///
+    /// welder.push_elem_glue_both("foo");
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("|foo|", &string);
+    /// ```
+    pub fn push_elem_glue_both<E>(&mut self, elem: E) -> &mut Self
+    where
+        T: Weldable<E>
+    {
+        self.glue.apply(&mut self.welded);
+        self.welded.weld_elem(elem);
+        self.glue.apply(&mut self.welded);
+        self.glues += 2;
+        self.count_elems(1);
+        self
+    }
+
+    /// Add each element with a glue on both sides of it, without consuming the `Welder`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let mut welder: Welder<_, String> = Welder::new('|');
+    ///
+    /// welder.push_elems_glue_both(vec!["foo", "bar"]);
+    ///
+    /// let string = welder.weld();
+    /// assert_eq!("|foo||bar|", &string);
+    /// ```
+    pub fn push_elems_glue_both<I>(&mut self, elems: I) -> &mut Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self.push_elem_glue_both(elem);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    #[test]
+    fn push_and_consuming_agree() {
+        let consumed: String = Welder::with_start(", ", "a")
+                                .elem("b")
+                                .elem_glue_right("c")
+                                .elems_glue_both(vec!["d", "e"])
+                                .weld();
+
+        let mut pushed: Welder<_, String> = Welder::with_start(", ", "a");
+        pushed.push_elem("b")
+              .push_elem_glue_right("c")
+              .push_elems_glue_both(vec!["d", "e"]);
+
+        assert_eq!(consumed, pushed.weld());
+    }
+}
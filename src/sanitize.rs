@@ -0,0 +1,233 @@
+use {Glue, Welder};
+
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+
+/// What to do with the control characters found in the elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlChars {
+    /// Remove the control characters.
+    Strip,
+    /// Replace each control character by the given one.
+    Replace(char),
+}
+
+/// A `Welder` that removes the control characters of the elements it is given.
+///
+/// It is created by the `sanitize` method of a `Welder`,
+/// the control characters (C0, DEL and C1) of every element are stripped or replaced.
+/// The glue is never sanitized.
+pub struct Sanitized<G> {
+    welder: Welder<G, String>,
+    control: ControlChars,
+    ansi: bool,
+}
+
+impl<G> Welder<G, String> {
+    /// Sanitize the control characters of every element added to the returned welder.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, ControlChars};
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "user:");
+    ///
+    /// let welder = welder.sanitize(ControlChars::Replace('?'));
+    /// let welder = welder.elem("root\rguest").elem("\x07admin");
+    ///
+    /// assert_eq!("user: root?guest ?admin", &welder.weld());
+    /// ```
+    pub fn sanitize(self, control: ControlChars) -> Sanitized<G> {
+        Sanitized { welder: self, control, ansi: false }
+    }
+}
+
+impl<G> Sanitized<G> {
+    /// Also remove the ANSI escape sequences entirely,
+    /// instead of only sanitizing their escape character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, ControlChars};
+    ///
+    /// let welder: Welder<_, String> = Welder::new(' ');
+    ///
+    /// let welder = welder.sanitize(ControlChars::Strip).strip_ansi();
+    /// let welder = welder.elem("\x1b[31mred\x1b[0m").elem("\x1b]0;title\x07plain");
+    ///
+    /// assert_eq!(" red plain", &welder.weld());
+    /// ```
+    pub fn strip_ansi(mut self) -> Self {
+        self.ansi = true;
+        self
+    }
+
+    /// Retrieve the accumulated values.
+    pub fn weld(self) -> String {
+        self.welder.weld()
+    }
+
+    /// Retrieve the underlying `Welder`, removing the sanitization.
+    pub fn into_inner(self) -> Welder<G, String> {
+        self.welder
+    }
+
+    fn sanitize(&self, elem: &str) -> String {
+        let mut sanitized = String::with_capacity(elem.len());
+        let mut chars = elem.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if self.ansi && c == ESC {
+                match chars.peek().cloned() {
+                    // Control Sequence Introducer, ends with a char in the @ to ~ range
+                    Some('[') => {
+                        chars.next();
+                        for c in chars.by_ref() {
+                            if ('@'..='~').contains(&c) { break }
+                        }
+                        continue
+                    },
+                    // Operating System Command, ends with a BEL or an ESC \
+                    Some(']') => {
+                        chars.next();
+                        while let Some(c) = chars.next() {
+                            if c == BEL { break }
+                            if c == ESC && chars.peek() == Some(&'\\') {
+                                chars.next();
+                                break
+                            }
+                        }
+                        continue
+                    },
+                    // Two characters escape sequences
+                    Some(next) if ('@'..='_').contains(&next) => {
+                        chars.next();
+                        continue
+                    },
+                    _ => (),
+                }
+            }
+
+            if c.is_control() {
+                if let ControlChars::Replace(replacement) = self.control {
+                    sanitized.push(replacement);
+                }
+            } else {
+                sanitized.push(c);
+            }
+        }
+
+        sanitized
+    }
+
+    /// Sanitize then add the element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, ControlChars};
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    ///
+    /// let welder = welder.sanitize(ControlChars::Strip).elem_no_glue("\nbar");
+    ///
+    /// assert_eq!("foobar", &welder.weld());
+    /// ```
+    pub fn elem_no_glue<E: AsRef<str>>(self, elem: E) -> Self {
+        let elem = self.sanitize(elem.as_ref());
+        Sanitized { welder: self.welder.elem_no_glue(elem), ..self }
+    }
+}
+
+impl<G> Sanitized<G>
+where
+    G: Glue<String>,
+{
+    /// Sanitize then add the element with a glue in front of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, ControlChars};
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "foo");
+    ///
+    /// let welder = welder.sanitize(ControlChars::Strip).elem("b\0ar");
+    ///
+    /// assert_eq!("foo, bar", &welder.weld());
+    /// ```
+    pub fn elem<E: AsRef<str>>(self, elem: E) -> Self {
+        let elem = self.sanitize(elem.as_ref());
+        Sanitized { welder: self.welder.elem::<String>(elem), ..self }
+    }
+
+    /// Sanitize then add each element with a glue in front of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, ControlChars};
+    ///
+    /// let welder: Welder<_, String> = Welder::new('|');
+    ///
+    /// let welder = welder.sanitize(ControlChars::Replace(' '));
+    /// let welder = welder.elems(vec!["foo\tbar", "baz\r\n"]);
+    ///
+    /// assert_eq!("|foo bar|baz  ", &welder.weld());
+    /// ```
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        for elem in elems {
+            self = self.elem(elem)
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Welder, ControlChars};
+
+    #[test]
+    fn sanitize_without_ansi_keeps_sequence_text() {
+        let welder: Welder<_, String> = Welder::new(' ');
+        let string = welder.sanitize(ControlChars::Strip).elem("\x1b[1mbold").weld();
+
+        assert_eq!(" [1mbold", &string);
+    }
+
+    #[test]
+    fn sanitize_ansi_unterminated_sequences() {
+        let welder: Welder<_, String> = Welder::new(' ');
+        let welder = welder.sanitize(ControlChars::Replace('?')).strip_ansi();
+
+        let string = welder.elem("a\x1b[1").elem("b\x1b]0;title").elem("c\x1b").elem("d\x1bMe").weld();
+
+        assert_eq!(" a b c? de", &string);
+    }
+
+    #[test]
+    fn sanitize_ansi_osc_string_terminator() {
+        let welder: Welder<_, String> = Welder::new(' ');
+        let welder = welder.sanitize(ControlChars::Strip).strip_ansi();
+
+        let string = welder.elem("\x1b]8;;http://example.com\x1b\\link\x1b]8;;\x1b\\").weld();
+
+        assert_eq!(" link", &string);
+    }
+
+    #[test]
+    fn sanitize_c1_controls() {
+        let welder: Welder<_, String> = Welder::new(' ');
+        let welder = welder.sanitize(ControlChars::Replace('?'));
+
+        let string = welder.elem("a\u{80}b\u{9b}31mc\u{9f}").elem("\u{a0}é").weld();
+
+        assert_eq!(" a?b?31mc? \u{a0}é", &string);
+    }
+}
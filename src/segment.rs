@@ -0,0 +1,419 @@
+use std::collections::{vec_deque, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Range;
+
+use {Glue, Weldable};
+
+/// A welder that keeps each element as its own segment,
+/// the glue is only given when the segments are welded.
+///
+/// The same segments can be welded as many times as needed, with different glues.
+/// Segments can be added at both ends, the glue always ends up between them.
+///
+/// # Examples
+///
+/// ```
+/// use welder::SegmentedWelder;
+///
+/// let welder = SegmentedWelder::with_start("foo").elem("bar").elem("baz");
+///
+/// let display: String = welder.weld(", ");
+/// let file: String = welder.weld('\n');
+///
+/// assert_eq!("foo, bar, baz", &display);
+/// assert_eq!("foo\nbar\nbaz", &file);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SegmentedWelder<E> {
+    segments: VecDeque<E>,
+}
+
+impl<E> Default for SegmentedWelder<E> {
+    fn default() -> Self {
+        SegmentedWelder::new()
+    }
+}
+
+impl<E> SegmentedWelder<E> {
+    /// Create an empty `SegmentedWelder`.
+    pub fn new() -> Self {
+        SegmentedWelder { segments: VecDeque::new() }
+    }
+
+    /// Create a `SegmentedWelder` with a first segment.
+    pub fn with_start(start: E) -> Self {
+        SegmentedWelder::new().elem(start)
+    }
+
+    /// Add the element as a new segment.
+    pub fn elem(mut self, elem: E) -> Self {
+        self.segments.push_back(elem);
+        self
+    }
+
+    /// Add the element as a new segment in front of the others.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("leaf");
+    ///
+    /// let welder = welder.elem_front("child").elem_front("parent");
+    ///
+    /// let chain: String = welder.weld(" :: ");
+    /// assert_eq!("parent :: child :: leaf", &chain);
+    /// ```
+    pub fn elem_front(mut self, elem: E) -> Self {
+        self.segments.push_front(elem);
+        self
+    }
+
+    /// Add each element as a new segment.
+    pub fn elems<I>(mut self, elems: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+    {
+        self.segments.extend(elems);
+        self
+    }
+
+    /// Add the element as a new segment, without consuming the `SegmentedWelder`.
+    pub fn push_elem(&mut self, elem: E) -> &mut Self {
+        self.segments.push_back(elem);
+        self
+    }
+
+    /// Add the element as a new segment in front of the others,
+    /// without consuming the `SegmentedWelder`.
+    pub fn push_elem_front(&mut self, elem: E) -> &mut Self {
+        self.segments.push_front(elem);
+        self
+    }
+
+    /// Insert the element as a new segment at the given position,
+    /// the following segments are shifted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the number of segments.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("-v").elem("input.txt");
+    ///
+    /// let welder = welder.insert_at(0, "cat");
+    ///
+    /// let string: String = welder.weld(' ');
+    /// assert_eq!("cat -v input.txt", &string);
+    /// ```
+    pub fn insert_at(mut self, index: usize, elem: E) -> Self {
+        self.segments.insert(index, elem);
+        self
+    }
+
+    /// Replace the segment at the given position by the element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("len=?").elem("data");
+    ///
+    /// let welder = welder.replace_at(0, "len=4");
+    ///
+    /// let string: String = welder.weld(", ");
+    /// assert_eq!("len=4, data", &string);
+    /// ```
+    pub fn replace_at(mut self, index: usize, elem: E) -> Self {
+        self.segments[index] = elem;
+        self
+    }
+
+    /// Remove the last segment, the glue in front of it is removed with it
+    /// as glues are only added when welding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let mut welder = SegmentedWelder::with_start("a").elem("b").elem("maybe");
+    ///
+    /// assert_eq!(Some("maybe"), welder.pop_elem());
+    ///
+    /// let string: String = welder.weld(", ");
+    /// assert_eq!("a, b", &string);
+    /// ```
+    pub fn pop_elem(&mut self) -> Option<E> {
+        self.segments.pop_back()
+    }
+
+    /// Remove the first segment.
+    pub fn pop_elem_front(&mut self) -> Option<E> {
+        self.segments.pop_front()
+    }
+
+    /// The number of segments.
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns `true` if there is no segment.
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Iterate over the segments, in order.
+    pub fn iter(&self) -> vec_deque::Iter<'_, E> {
+        self.segments.iter()
+    }
+
+    /// Retrieve the segments.
+    pub fn into_segments(self) -> Vec<E> {
+        self.segments.into()
+    }
+
+    /// Weld clones of the segments with the glue between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::new().elems(vec![12, 14, 16]);
+    ///
+    /// let vec: Vec<_> = welder.weld(0);
+    /// assert_eq!(&[12, 0, 14, 0, 16], vec.as_slice());
+    ///
+    /// let vec: Vec<_> = welder.weld([1, 1]);
+    /// assert_eq!(&[12, 1, 1, 14, 1, 1, 16], vec.as_slice());
+    /// ```
+    pub fn weld<G, T>(&self, glue: G) -> T
+    where
+        E: Clone,
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        weld_segments(self.segments.iter().cloned(), glue)
+    }
+
+    /// Weld clones of the segments from the last one to the first one,
+    /// with the glue between them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("file.rs").elem("src").elem("crate");
+    ///
+    /// let path: String = welder.weld_reversed('/');
+    /// assert_eq!("crate/src/file.rs", &path);
+    /// ```
+    pub fn weld_reversed<G, T>(&self, glue: G) -> T
+    where
+        E: Clone,
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        weld_segments(self.segments.iter().rev().cloned(), glue)
+    }
+
+    /// Weld clones of the segments sorted in ascending order,
+    /// the segments stored are left in the order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("serde").elem("alloc").elem("std");
+    ///
+    /// let features: String = welder.weld_sorted(',');
+    /// assert_eq!("alloc,serde,std", &features);
+    /// ```
+    pub fn weld_sorted<G, T>(&self, glue: G) -> T
+    where
+        E: Clone + Ord,
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        let mut sorted: Vec<_> = self.segments.iter().collect();
+        sorted.sort();
+        weld_segments(sorted.into_iter().cloned(), glue)
+    }
+
+    /// Weld clones of the segments, skipping the ones equal to a previous segment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("b").elem("a").elem("b").elem("c");
+    ///
+    /// let string: String = welder.weld_dedup(' ');
+    /// assert_eq!("b a c", &string);
+    /// ```
+    pub fn weld_dedup<G, T>(&self, glue: G) -> T
+    where
+        E: Clone + Eq + Hash,
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        let mut seen = HashSet::new();
+        let unique = self.segments.iter().filter(|segment| seen.insert(*segment));
+        weld_segments(unique.cloned(), glue)
+    }
+
+    /// Weld clones of the segments sorted in ascending order, without duplicates,
+    /// the output does not depend on the order the segments were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let first = SegmentedWelder::new().elems(vec!["std", "serde", "std"]);
+    /// let second = SegmentedWelder::new().elems(vec!["serde", "std"]);
+    ///
+    /// let first: String = first.weld_sorted_dedup(',');
+    /// let second: String = second.weld_sorted_dedup(',');
+    ///
+    /// assert_eq!("serde,std", &first);
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn weld_sorted_dedup<G, T>(&self, glue: G) -> T
+    where
+        E: Clone + Ord,
+        G: Glue<T>,
+        T: Default + Weldable<E>,
+    {
+        let mut sorted: Vec<_> = self.segments.iter().collect();
+        sorted.sort();
+        sorted.dedup();
+        weld_segments(sorted.into_iter().cloned(), glue)
+    }
+
+    /// Weld clones of the segments into a `String` and return,
+    /// along with it, the byte range each segment occupies in it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::SegmentedWelder;
+    ///
+    /// let welder = SegmentedWelder::with_start("cargo").elem("biuld").elem("--release");
+    ///
+    /// let (line, spans) = welder.weld_with_spans(' ');
+    ///
+    /// assert_eq!("cargo biuld --release", &line);
+    /// assert_eq!(vec![0..5, 6..11, 12..21], spans);
+    /// assert_eq!("biuld", &line[spans[1].clone()]);
+    /// ```
+    pub fn weld_with_spans<G>(&self, mut glue: G) -> (String, Vec<Range<usize>>)
+    where
+        E: Clone,
+        G: Glue<String>,
+        String: Weldable<E>,
+    {
+        let mut welded = String::new();
+        let mut spans = Vec::with_capacity(self.segments.len());
+        for (i, segment) in self.segments.iter().enumerate() {
+            if i != 0 {
+                glue.apply(&mut welded);
+            }
+            let start = welded.len();
+            welded.weld_elem(segment.clone());
+            spans.push(start..welded.len());
+        }
+        (welded, spans)
+    }
+}
+
+fn weld_segments<I, G, T>(segments: I, mut glue: G) -> T
+where
+    I: IntoIterator,
+    G: Glue<T>,
+    T: Default + Weldable<I::Item>,
+{
+    let mut welded = T::default();
+    for (i, segment) in segments.into_iter().enumerate() {
+        if i != 0 {
+            glue.apply(&mut welded);
+        }
+        welded.weld_elem(segment);
+    }
+    welded
+}
+
+impl<'a, E> IntoIterator for &'a SegmentedWelder<E> {
+    type Item = &'a E;
+    type IntoIter = vec_deque::Iter<'a, E>;
+
+    fn into_iter(self) -> vec_deque::Iter<'a, E> {
+        self.segments.iter()
+    }
+}
+
+impl<E> IntoIterator for SegmentedWelder<E> {
+    type Item = E;
+    type IntoIter = vec_deque::IntoIter<E>;
+
+    fn into_iter(self) -> vec_deque::IntoIter<E> {
+        self.segments.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SegmentedWelder;
+
+    #[test]
+    fn segmented_welder_welds_again() {
+        let mut welder = SegmentedWelder::new();
+        let empty: String = welder.weld(", ");
+        assert_eq!("", &empty);
+
+        welder.push_elem(String::from("a")).push_elem(String::from("b"));
+        let first: String = welder.weld(", ");
+        let second: String = welder.weld(String::from(" | "));
+
+        assert_eq!("a, b", &first);
+        assert_eq!("a | b", &second);
+        assert_eq!(2, welder.iter().count());
+    }
+
+    #[test]
+    fn segmented_welder_grows_at_both_ends() {
+        let mut welder = SegmentedWelder::new();
+        welder.push_elem_front(2).push_elem(3).push_elem_front(1);
+
+        let vec: Vec<_> = welder.weld(0);
+        assert_eq!(&[1, 0, 2, 0, 3], vec.as_slice());
+
+        assert_eq!(Some(1), welder.pop_elem_front());
+        assert_eq!(Some(3), welder.pop_elem());
+        assert_eq!(vec![2], welder.into_segments());
+    }
+
+    #[test]
+    fn spans_skip_the_glue() {
+        let welder = SegmentedWelder::new().elems(vec!["", "é", "ab"]);
+
+        let (string, spans) = welder.weld_with_spans(String::from(", "));
+
+        assert_eq!(", é, ab", &string);
+        assert_eq!(vec![0..0, 2..4, 6..8], spans);
+    }
+}
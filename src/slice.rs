@@ -0,0 +1,69 @@
+use {Capacity, Glue, Weldable, Welder};
+
+/// An extension trait to weld the items of a slice, an array or a `Vec`.
+pub trait WeldSlice<E> {
+    /// Weld clones of all the items with the glue between them,
+    /// the room needed is reserved up front when the target knows the length
+    /// of the items and of the glue, see `Welder::elems_reserved` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::WeldSlice;
+    ///
+    /// let string: String = ["a", "b", "c"].weld(", ");
+    /// assert_eq!("a, b, c", &string);
+    ///
+    /// let vec: Vec<_> = vec![1, 2, 3].weld(0);
+    /// assert_eq!(&[1, 0, 2, 0, 3], vec.as_slice());
+    /// ```
+    fn weld<G, T>(&self, glue: G) -> T
+    where
+        G: Glue<T>,
+        T: Capacity + Weldable<E>;
+}
+
+impl<E: Clone> WeldSlice<E> for [E] {
+    fn weld<G, T>(&self, glue: G) -> T
+    where
+        G: Glue<T>,
+        T: Capacity + Weldable<E>,
+    {
+        let (first, rest) = match self.split_first() {
+            Some((first, rest)) => (first, rest),
+            None => return Welder::with_capacity(glue, 0).weld(),
+        };
+
+        let probe = T::with_capacity(0);
+        let elems: Option<usize> = self.iter().map(|elem| probe.len_hint(elem)).sum();
+        let room = elems.and_then(|elems| {
+            let glues = glue.len_hint(&probe)?.checked_mul(rest.len())?;
+            elems.checked_add(glues)
+        });
+
+        let welder = Welder::with_capacity(glue, room.unwrap_or(0)).elem_no_glue(first.clone());
+        match room {
+            Some(_) => welder.elems(rest.iter().cloned()).weld(),
+            None => welder.elems_reserved(rest.iter().cloned()).weld(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WeldSlice;
+
+    #[test]
+    fn weld_slice_reserves_room() {
+        let string: String = ["foo"; 100].weld(", ");
+        assert_eq!(100 * 5 - 2, string.len());
+        assert_eq!(string.len(), string.capacity());
+
+        let vec: Vec<_> = [1, 2, 3, 4].weld([0, 0]);
+        assert_eq!(4 + 3 * 2, vec.len());
+        assert_eq!(vec.len(), vec.capacity());
+
+        let empty: &[&str] = &[];
+        assert_eq!("", &empty.weld::<_, String>(", "));
+    }
+}
@@ -0,0 +1,206 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Seek, SeekFrom, Write};
+
+use tempfile;
+
+use Welder;
+
+/// The default number of bytes a `SpillBuffer` keeps in memory.
+pub const DEFAULT_SPILL_THRESHOLD: usize = 8 * 1024 * 1024;
+
+/// A target that keeps the welded bytes in memory until they reach a threshold,
+/// it then moves them to a temporary file and writes everything else into it.
+///
+/// Writing to the file can fail, the first error encountered is kept
+/// and returned when the buffer is finished, nothing is written after it.
+pub struct SpillBuffer {
+    threshold: usize,
+    len: usize,
+    memory: Vec<u8>,
+    file: Option<BufWriter<File>>,
+    error: Option<io::Error>,
+}
+
+/// Where the welded bytes of a `SpillBuffer` ended up.
+#[derive(Debug)]
+pub enum Spilled {
+    /// The bytes stayed under the threshold.
+    Memory(Vec<u8>),
+    /// The bytes have been written to a temporary file,
+    /// it is rewound to the start and deleted once closed.
+    File(File),
+}
+
+impl SpillBuffer {
+    /// Create a buffer that keeps at most `threshold` bytes in memory.
+    pub fn new(threshold: usize) -> Self {
+        SpillBuffer {
+            threshold,
+            len: 0,
+            memory: Vec::new(),
+            file: None,
+            error: None,
+        }
+    }
+
+    /// The number of bytes welded into this buffer.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no byte has been welded into this buffer.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the bytes have been moved to a temporary file.
+    pub fn is_spilled(&self) -> bool {
+        self.file.is_some()
+    }
+
+    /// Flush the temporary file, if any, and retrieve the welded bytes.
+    pub fn finish(self) -> io::Result<Spilled> {
+        if let Some(error) = self.error {
+            return Err(error)
+        }
+
+        match self.file {
+            Some(writer) => {
+                let mut file = writer.into_inner().map_err(|e| e.into_error())?;
+                file.seek(SeekFrom::Start(0))?;
+                Ok(Spilled::File(file))
+            },
+            None => Ok(Spilled::Memory(self.memory)),
+        }
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        if self.error.is_some() {
+            return
+        }
+        self.len += bytes.len();
+
+        if let Some(ref mut writer) = self.file {
+            if let Err(error) = writer.write_all(bytes) {
+                self.error = Some(error);
+            }
+            return
+        }
+
+        if self.memory.len() + bytes.len() <= self.threshold {
+            self.memory.extend_from_slice(bytes);
+            return
+        }
+
+        let result = tempfile::tempfile().and_then(|file| {
+            let mut writer = BufWriter::new(file);
+            writer.write_all(&self.memory)?;
+            writer.write_all(bytes)?;
+            Ok(writer)
+        });
+
+        match result {
+            Ok(writer) => {
+                self.memory = Vec::new();
+                self.file = Some(writer);
+            },
+            Err(error) => self.error = Some(error),
+        }
+    }
+}
+
+impl Default for SpillBuffer {
+    fn default() -> Self {
+        SpillBuffer::new(DEFAULT_SPILL_THRESHOLD)
+    }
+}
+
+impl_extend_bytes!(SpillBuffer);
+
+impl<G> Welder<G, SpillBuffer> {
+    /// Create an empty `Welder` that spills its output to a temporary file
+    /// once it exceeds `threshold` bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::Read;
+    /// use welder::{Welder, Spilled};
+    ///
+    /// let welder = Welder::spilling(", ", 8);
+    ///
+    /// let welder = welder.elem("foo").elem("bar").elem("baz");
+    ///
+    /// match welder.weld_spilled().unwrap() {
+    ///     Spilled::File(mut file) => {
+    ///         let mut string = String::new();
+    ///         file.read_to_string(&mut string).unwrap();
+    ///         assert_eq!(", foo, bar, baz", &string);
+    ///     },
+    ///     Spilled::Memory(_) => panic!("the output exceeds the threshold"),
+    /// }
+    /// ```
+    pub fn spilling(glue: G, threshold: usize) -> Self {
+        Welder {
+            glue,
+            welded: SpillBuffer::new(threshold),
+            delta_start: 0,
+            elems: 0,
+            glues: 0,
+            chunk_open: false,
+        }
+    }
+
+    /// Retrieve the accumulated values, either from memory or from the temporary file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, Spilled};
+    ///
+    /// let welder = Welder::spilling(' ', 1024).elem("foo");
+    ///
+    /// match welder.weld_spilled().unwrap() {
+    ///     Spilled::Memory(bytes) => assert_eq!(b" foo", bytes.as_slice()),
+    ///     Spilled::File(_) => panic!("the output does not exceed the threshold"),
+    /// }
+    /// ```
+    pub fn weld_spilled(self) -> io::Result<Spilled> {
+        self.welded.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+    use super::{SpillBuffer, Spilled};
+    use Welder;
+
+    #[test]
+    fn spill_exactly_at_threshold_stays_in_memory() {
+        let welder = Welder::spilling(0u8, 3).elem_no_glue(&[1, 2, 3][..]);
+
+        match welder.weld_spilled().unwrap() {
+            Spilled::Memory(bytes) => assert_eq!(vec![1, 2, 3], bytes),
+            Spilled::File(_) => panic!("spilled at the threshold"),
+        }
+    }
+
+    #[test]
+    fn spill_keeps_writing_after_spilling() {
+        let mut buffer = SpillBuffer::new(2);
+        buffer.extend(vec!["ab", "cd", "ef"]);
+
+        assert!(buffer.is_spilled());
+        assert_eq!(6, buffer.len());
+
+        match buffer.finish().unwrap() {
+            Spilled::File(mut file) => {
+                let mut string = String::new();
+                file.read_to_string(&mut string).unwrap();
+                assert_eq!("abcdef", &string);
+            },
+            Spilled::Memory(_) => panic!("did not spill"),
+        }
+    }
+}
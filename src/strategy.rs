@@ -0,0 +1,106 @@
+//! Strategies of the `proptest` crate to property-test code that parses welded formats.
+//!
+//! A `WeldCase` is generated along with the output it is expected to be welded into,
+//! the expected output is computed without using a welder.
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use {Unwelder, Welder};
+
+/// The elements, the glue and the glue layout of a weld along with its expected output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeldCase {
+    /// The elements to weld, in order.
+    pub elems: Vec<String>,
+    /// The glue put between the elements.
+    pub glue: String,
+    /// Whether the output starts with a glue, like with `Welder::new` followed by `elem`s.
+    pub leading: bool,
+    /// Whether the output ends with a glue, like with `elem_glue_right`.
+    pub trailing: bool,
+    /// The expected output.
+    pub welded: String,
+}
+
+impl WeldCase {
+    fn new(elems: Vec<String>, glue: String, leading: bool, trailing: bool) -> WeldCase {
+        let mut welded = String::new();
+        if leading && !elems.is_empty() {
+            welded.push_str(&glue);
+        }
+        welded.push_str(&elems.join(&glue));
+        if trailing && !elems.is_empty() {
+            welded.push_str(&glue);
+        }
+        WeldCase { elems, glue, leading, trailing, welded }
+    }
+
+    /// Weld the elements with a `Welder` following the glue layout of this case.
+    pub fn weld(&self) -> String {
+        let elems = self.elems.iter().map(String::as_str);
+        let glue = self.glue.as_str();
+        let mut welder: Welder<_, String> = Welder::new(glue);
+
+        for (i, elem) in elems.enumerate() {
+            let last = i + 1 == self.elems.len();
+            welder = match (i == 0 && !self.leading, last && self.trailing) {
+                (true, true) => welder.elem_glue_right(elem),
+                (true, false) => welder.elem_no_glue(elem),
+                (false, true) => welder.elem_glue_both(elem),
+                (false, false) => welder.elem(elem),
+            };
+        }
+        welder.weld()
+    }
+
+    /// Create an `Unwelder` that splits the output of this case.
+    pub fn unwelder(&self) -> Unwelder<&str> {
+        let mut unwelder = Unwelder::new(self.glue.as_str());
+        if self.leading {
+            unwelder = unwelder.leading_glue();
+        }
+        if self.trailing {
+            unwelder = unwelder.trailing_glue();
+        }
+        unwelder
+    }
+}
+
+/// Generate weld cases from the given element and glue strategies,
+/// with at most `max_elems` elements and an arbitrary glue layout.
+pub fn weld_cases<E, G>(elem: E, glue: G, max_elems: usize) -> impl Strategy<Value = WeldCase>
+where
+    E: Strategy<Value = String>,
+    G: Strategy<Value = String>,
+{
+    (vec(elem, 0..=max_elems), glue, any::<bool>(), any::<bool>())
+        .prop_map(|(elems, glue, leading, trailing)| WeldCase::new(elems, glue, leading, trailing))
+}
+
+/// Generate weld cases where no element contains the glue,
+/// the output of these cases can always be split back into the same elements.
+///
+/// Elements and glues are drawn from disjoint alphabets, and elements are never empty,
+/// an empty output is then only produced by zero elements.
+pub fn unambiguous_weld_cases(max_elems: usize) -> impl Strategy<Value = WeldCase> {
+    weld_cases("[a-z0-9 ]{1,8}", "[,;|&]{1,3}", max_elems)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::{weld_cases, unambiguous_weld_cases};
+
+    proptest! {
+        #[test]
+        fn welder_matches_expected(case in weld_cases(".{0,5}", ".{0,3}", 10)) {
+            prop_assert_eq!(&case.welded, &case.weld());
+        }
+
+        #[test]
+        fn unwelder_round_trips(case in unambiguous_weld_cases(10)) {
+            prop_assert_eq!(case.elems.clone(), case.unwelder().unweld(&case.welded));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use {Glue, Weldable, Welder};
+
+/// A `Welder` whose output is wrapped between an opening and a closing glue.
+///
+/// It is created by the `surround` method of a `Welder`, which only wraps
+/// a non-empty output, or by the `wrap` method, which always wraps it.
+pub struct Surrounded<G, T, O, C> {
+    welder: Welder<G, T>,
+    open: O,
+    close: C,
+    always: bool,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Wrap the output between the two glues when it is welded, an empty
+    /// output stays empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(", ");
+    ///
+    /// let welder = welder.surround('[', ']').elem_no_glue("foo").elem("bar");
+    /// assert_eq!("[foo, bar]", &welder.weld());
+    ///
+    /// let welder: Welder<&str, String> = Welder::new(", ");
+    /// assert_eq!("", &welder.surround('[', ']').weld());
+    /// ```
+    pub fn surround<O, C>(self, open: O, close: C) -> Surrounded<G, T, O, C> {
+        Surrounded { welder: self, open, close, always: false }
+    }
+
+    /// Wrap the output between the two glues when it is welded,
+    /// an empty output is wrapped too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "a").elems(vec!["b", "c"]);
+    /// assert_eq!("[a, b, c]", &welder.wrap('[', ']').weld());
+    ///
+    /// let welder: Welder<&str, String> = Welder::new(", ");
+    /// assert_eq!("[]", &welder.wrap('[', ']').weld());
+    /// ```
+    pub fn wrap<O, C>(self, prefix: O, suffix: C) -> Surrounded<G, T, O, C> {
+        Surrounded { welder: self, open: prefix, close: suffix, always: true }
+    }
+}
+
+impl<G, T, O, C> Surrounded<G, T, O, C> {
+    /// Retrieve the `Welder`, the output will not be wrapped anymore.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Add the element without any glue, see `Welder::elem_no_glue`.
+    pub fn elem_no_glue<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Surrounded { welder: self.welder.elem_no_glue(elem), ..self }
+    }
+
+    /// Add each element without any glue, see `Welder::elems_no_glue`.
+    pub fn elems_no_glue<I>(self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        Surrounded { welder: self.welder.elems_no_glue(elems), ..self }
+    }
+}
+
+impl<G, T, O, C> Surrounded<G, T, O, C>
+where
+    G: Glue<T>,
+{
+    /// Add the element with a glue in front of it, see `Welder::elem`.
+    pub fn elem<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Surrounded { welder: self.welder.elem(elem), ..self }
+    }
+
+    /// Add each element with a glue in front of it, see `Welder::elems`.
+    pub fn elems<I>(self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        Surrounded { welder: self.welder.elems(elems), ..self }
+    }
+
+    /// Add the element with a glue to the right of it, see `Welder::elem_glue_right`.
+    pub fn elem_glue_right<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Surrounded { welder: self.welder.elem_glue_right(elem), ..self }
+    }
+
+    /// Add the element with a glue on both sides of it, see `Welder::elem_glue_both`.
+    pub fn elem_glue_both<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Surrounded { welder: self.welder.elem_glue_both(elem), ..self }
+    }
+}
+
+impl<G, O, C> Surrounded<G, String, O, C>
+where
+    O: Glue<String>,
+    C: Glue<String>,
+{
+    /// Retrieve the accumulated values between the opening and the closing glues,
+    /// or nothing if no value has been accumulated and the welder has been surrounded.
+    pub fn weld(mut self) -> String {
+        let welded = self.welder.weld();
+        if welded.is_empty() && !self.always {
+            return welded
+        }
+
+        let mut surrounded = String::with_capacity(welded.len() + 2);
+        self.open.apply(&mut surrounded);
+        surrounded.push_str(&welded);
+        self.close.apply(&mut surrounded);
+        surrounded
+    }
+}
+
+impl<G, E, O, C> Surrounded<G, Vec<E>, O, C>
+where
+    O: Glue<Vec<E>>,
+    C: Glue<Vec<E>>,
+{
+    /// Retrieve the accumulated values between the opening and the closing glues,
+    /// or nothing if no value has been accumulated and the welder has been surrounded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 1).elem(2);
+    ///
+    /// let vec = welder.surround(-1, -2).weld();
+    ///
+    /// assert_eq!(&[-1, 1, 0, 2, -2], vec.as_slice());
+    ///
+    /// let welder: Welder<i32, Vec<i32>> = Welder::new(0);
+    ///
+    /// assert_eq!(&[-1, -2], welder.wrap(-1, -2).weld().as_slice());
+    /// ```
+    pub fn weld(mut self) -> Vec<E> {
+        let welded = self.welder.weld();
+        if welded.is_empty() && !self.always {
+            return welded
+        }
+
+        let mut surrounded = Vec::with_capacity(welded.len() + 2);
+        self.open.apply(&mut surrounded);
+        surrounded.extend(welded);
+        self.close.apply(&mut surrounded);
+        surrounded
+    }
+}
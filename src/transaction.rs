@@ -0,0 +1,137 @@
+use {Glue, Snapshot, Truncate, Weldable, Welder};
+
+/// A group of insertions that is either entirely kept or entirely discarded.
+///
+/// It is created by the `begin` method of a `Welder`,
+/// the elements are only part of the `Welder` once the transaction is committed.
+/// A rolled back transaction also leaves the state of the glue untouched.
+pub struct Transaction<G, T> {
+    welder: Welder<G, T>,
+    snapshot: Snapshot<G>,
+}
+
+impl<G: Clone, T: Truncate> Welder<G, T> {
+    /// Start a transaction, the elements added to it will only be part of
+    /// this `Welder` if the transaction is committed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(", ", "foo");
+    ///
+    /// let welder = welder.begin().elem("bar").elem("baz").commit();
+    /// let welder = welder.begin().elem("boat").rollback();
+    ///
+    /// assert_eq!("foo, bar, baz", &welder.weld());
+    /// ```
+    pub fn begin(self) -> Transaction<G, T> {
+        Transaction { snapshot: self.snapshot(), welder: self }
+    }
+}
+
+impl<G, T> Transaction<G, T> {
+    /// Keep every element added during the transaction and return the `Welder`.
+    pub fn commit(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Add the element without any glue, see `Welder::elem_no_glue`.
+    pub fn elem_no_glue<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Transaction { welder: self.welder.elem_no_glue(elem), ..self }
+    }
+
+    /// Add each element without any glue, see `Welder::elems_no_glue`.
+    pub fn elems_no_glue<I>(self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        Transaction { welder: self.welder.elems_no_glue(elems), ..self }
+    }
+}
+
+impl<G, T: Truncate> Transaction<G, T> {
+    /// Discard every element added during the transaction and return the `Welder`.
+    pub fn rollback(self) -> Welder<G, T> {
+        self.welder.restore(self.snapshot)
+    }
+}
+
+impl<G, T> Transaction<G, T>
+where
+    G: Glue<T>,
+{
+    /// Add the element with a glue in front of it, see `Welder::elem`.
+    pub fn elem<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Transaction { welder: self.welder.elem(elem), ..self }
+    }
+
+    /// Add each element with a glue in front of it, see `Welder::elems`.
+    pub fn elems<I>(self, elems: I) -> Self
+    where
+        I: IntoIterator,
+        T: Weldable<I::Item>,
+    {
+        Transaction { welder: self.welder.elems(elems), ..self }
+    }
+
+    /// Add the element with a glue to the right of it, see `Welder::elem_glue_right`.
+    pub fn elem_glue_right<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Transaction { welder: self.welder.elem_glue_right(elem), ..self }
+    }
+
+    /// Add the element with a glue on both sides of it, see `Welder::elem_glue_both`.
+    pub fn elem_glue_both<E>(self, elem: E) -> Self
+    where
+        T: Weldable<E>
+    {
+        Transaction { welder: self.welder.elem_glue_both(elem), ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    fn parse_fields(welder: Welder<char, String>, fields: &[&str]) -> Welder<char, String> {
+        let mut transaction = welder.begin();
+        for field in fields {
+            match field.parse::<u32>() {
+                Ok(n) => transaction = transaction.elem(n.to_string()),
+                Err(_) => return transaction.rollback(),
+            }
+        }
+        transaction.commit()
+    }
+
+    #[test]
+    fn transaction_all_or_nothing() {
+        let welder = Welder::with_start(',', "0");
+
+        let welder = parse_fields(welder, &["1", "2"]);
+        let welder = parse_fields(welder, &["3", "four", "5"]);
+        let welder = parse_fields(welder, &["6"]);
+
+        assert_eq!("0,1,2,6", &welder.weld());
+    }
+
+    #[test]
+    fn transaction_rollback_restores_the_glue() {
+        let welder: Welder<_, String> = Welder::with_first_glue('?', '&').elem_no_glue("/s");
+
+        let welder = welder.begin().elem("x=1").rollback().elem("a=1");
+
+        assert_eq!("/s?a=1", &welder.weld());
+    }
+}
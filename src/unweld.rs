@@ -0,0 +1,177 @@
+/// The inverse of a `Welder`, split a welded value back into its elements.
+///
+/// By default the welded value is expected to start with an element
+/// and to end with an element, like the output of `Welder::with_start` followed by `elem`s.
+/// An empty welded value gives no element at all.
+pub struct Unwelder<G> {
+    glue: G,
+    leading: bool,
+    trailing: bool,
+}
+
+impl<G> Unwelder<G> {
+    /// Create an `Unwelder` just by defining the glue to split on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, Unwelder};
+    ///
+    /// let string: String = Welder::with_start(", ", "foo").elem("bar").weld();
+    ///
+    /// let unwelder = Unwelder::new(", ");
+    ///
+    /// assert_eq!(vec!["foo", "bar"], unwelder.unweld(&string));
+    /// ```
+    pub fn new(glue: G) -> Self {
+        Unwelder {
+            glue,
+            leading: false,
+            trailing: false,
+        }
+    }
+
+    /// Expect the welded value to start with a glue, like the output of `Welder::new` followed by `elem`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, Unwelder};
+    ///
+    /// let string: String = Welder::new(' ').elem("foo").elem("bar").weld();
+    ///
+    /// let unwelder = Unwelder::new(' ').leading_glue();
+    ///
+    /// assert_eq!(vec!["foo", "bar"], unwelder.unweld(&string));
+    /// ```
+    pub fn leading_glue(mut self) -> Self {
+        self.leading = true;
+        self
+    }
+
+    /// Expect the welded value to end with a glue, like the output of `elem_glue_right`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, Unwelder};
+    ///
+    /// let string: String = Welder::new(';').elems_glue_right(vec!["foo", "bar"]).weld();
+    ///
+    /// let unwelder = Unwelder::new(';').trailing_glue();
+    ///
+    /// assert_eq!(vec!["foo", "bar"], unwelder.unweld(&string));
+    /// ```
+    pub fn trailing_glue(mut self) -> Self {
+        self.trailing = true;
+        self
+    }
+
+    /// Split a welded slice on the glue items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::{Welder, Unwelder};
+    ///
+    /// let vec: Vec<_> = Welder::with_start(0, 12).elem(14).elem(16).weld();
+    ///
+    /// let unwelder = Unwelder::new(0);
+    ///
+    /// let elems: Vec<&[i32]> = vec![&[12], &[14], &[16]];
+    /// assert_eq!(elems, unwelder.unweld_slice(&vec));
+    /// ```
+    pub fn unweld_slice<'a>(&self, welded: &'a [G]) -> Vec<&'a [G]>
+    where
+        G: PartialEq
+    {
+        let mut welded = welded;
+        if self.leading && welded.first() == Some(&self.glue) {
+            welded = &welded[1..];
+        }
+        if self.trailing && welded.last() == Some(&self.glue) {
+            welded = &welded[..welded.len() - 1];
+        }
+        if welded.is_empty() {
+            return Vec::new()
+        }
+        welded.split(|item| *item == self.glue).collect()
+    }
+}
+
+impl Unwelder<&str> {
+    /// Split a welded string on the glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Unwelder;
+    ///
+    /// let unwelder = Unwelder::new(" AND ");
+    ///
+    /// assert_eq!(vec!["a = 1", "b = 2"], unwelder.unweld("a = 1 AND b = 2"));
+    /// assert!(unwelder.unweld("").is_empty());
+    /// ```
+    pub fn unweld<'a>(&self, welded: &'a str) -> Vec<&'a str> {
+        let mut welded = welded;
+        if self.leading && welded.starts_with(self.glue) {
+            welded = &welded[self.glue.len()..];
+        }
+        if self.trailing && welded.ends_with(self.glue) {
+            welded = &welded[..welded.len() - self.glue.len()];
+        }
+        if welded.is_empty() {
+            return Vec::new()
+        }
+        welded.split(self.glue).collect()
+    }
+}
+
+impl Unwelder<char> {
+    /// Split a welded string on the glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Unwelder;
+    ///
+    /// let unwelder = Unwelder::new('&');
+    ///
+    /// assert_eq!(vec!["a=1", "b=2", ""], unwelder.unweld("a=1&b=2&"));
+    /// ```
+    pub fn unweld<'a>(&self, welded: &'a str) -> Vec<&'a str> {
+        let mut welded = welded;
+        if self.leading && welded.starts_with(self.glue) {
+            welded = &welded[self.glue.len_utf8()..];
+        }
+        if self.trailing && welded.ends_with(self.glue) {
+            welded = &welded[..welded.len() - self.glue.len_utf8()];
+        }
+        if welded.is_empty() {
+            return Vec::new()
+        }
+        welded.split(self.glue).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {Welder, Unwelder};
+
+    #[test]
+    fn unweld_round_trip() {
+        let elems = vec!["foo", "", "bar", "baz"];
+        let string: String = Welder::new(", ").elems(elems.clone()).weld();
+
+        assert_eq!(elems, Unwelder::new(", ").leading_glue().unweld(&string));
+    }
+
+    #[test]
+    fn unweld_both_glues() {
+        let string: String = Welder::new('|').elems_glue_both(vec!["a", "b"]).weld();
+
+        let unwelder = Unwelder::new('|').leading_glue().trailing_glue();
+
+        assert_eq!(vec!["a", "", "b"], unwelder.unweld(&string));
+    }
+}
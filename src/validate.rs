@@ -0,0 +1,164 @@
+use {Glue, Weldable, Welder};
+
+/// A `Welder` that checks every element before adding it.
+///
+/// It is created by the `validate_each` method of a `Welder`,
+/// an element rejected by the validator is never added to the output.
+pub struct Validated<G, T, F> {
+    welder: Welder<G, T>,
+    validator: F,
+}
+
+impl<G, T> Welder<G, T> {
+    /// Check every element added with the `try_elem*` methods of the returned welder,
+    /// making the insertion fail when the validator returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(',', "foo");
+    ///
+    /// let mut welder = welder.validate_each(|elem: &&str| {
+    ///     if elem.contains(',') { Err("element contains the glue") } else { Ok(()) }
+    /// });
+    ///
+    /// welder.try_elem("bar").unwrap();
+    /// assert_eq!(Some("element contains the glue"), welder.try_elem("b,az").err());
+    /// assert_eq!("foo,bar", &welder.weld());
+    /// ```
+    pub fn validate_each<F>(self, validator: F) -> Validated<G, T, F> {
+        Validated { welder: self, validator }
+    }
+}
+
+impl<G, T, F> Validated<G, T, F> {
+    /// Retrieve the accumulated values, every one of them has been validated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(' ');
+    /// let mut welder = welder.validate_each(|elem: &&str| {
+    ///     if elem.is_ascii() { Ok(()) } else { Err(()) }
+    /// });
+    ///
+    /// welder.try_elems(vec!["foo", "bar"]).unwrap();
+    ///
+    /// assert_eq!(" foo bar", &welder.weld());
+    /// ```
+    pub fn weld(self) -> T {
+        self.welder.weld()
+    }
+
+    /// Retrieve the underlying `Welder`, removing the validator.
+    pub fn into_inner(self) -> Welder<G, T> {
+        self.welder
+    }
+
+    /// Validate then add the element without any glue.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::with_start(' ', "foo");
+    /// let mut welder = welder.validate_each(|elem: &&str| {
+    ///     if elem.len() <= 3 { Ok(()) } else { Err(elem.len()) }
+    /// });
+    ///
+    /// welder.try_elem_no_glue("bar").unwrap();
+    /// assert_eq!(Some(5), welder.try_elem_no_glue("bazzz").err());
+    /// ```
+    pub fn try_elem_no_glue<E, Er>(&mut self, elem: E) -> Result<&mut Self, Er>
+    where
+        F: FnMut(&E) -> Result<(), Er>,
+        T: Weldable<E>
+    {
+        (self.validator)(&elem)?;
+        self.welder.push_elem_no_glue(elem);
+        Ok(self)
+    }
+}
+
+impl<G, T, F> Validated<G, T, F>
+where
+    G: Glue<T>,
+{
+    /// Validate then add the element with a glue in front of it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, Vec<_>> = Welder::with_start(0, 12);
+    /// let mut welder = welder.validate_each(|elem: &i32| {
+    ///     if *elem != 0 { Ok(()) } else { Err("element is the glue") }
+    /// });
+    ///
+    /// welder.try_elem(14).unwrap();
+    /// assert!(welder.try_elem(0).is_err());
+    /// ```
+    pub fn try_elem<E, Er>(&mut self, elem: E) -> Result<&mut Self, Er>
+    where
+        F: FnMut(&E) -> Result<(), Er>,
+        T: Weldable<E>
+    {
+        (self.validator)(&elem)?;
+        self.welder.push_elem(elem);
+        Ok(self)
+    }
+
+    /// Validate then add each element with a glue in front of it,
+    /// stopping at the first element rejected, the elements before it are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use welder::Welder;
+    ///
+    /// let welder: Welder<_, String> = Welder::new(' ');
+    /// let mut welder = welder.validate_each(|elem: &&str| {
+    ///     if elem.is_empty() { Err("empty element") } else { Ok(()) }
+    /// });
+    ///
+    /// assert!(welder.try_elems(vec!["foo", "", "bar"]).is_err());
+    /// assert_eq!(" foo", &welder.weld());
+    /// ```
+    pub fn try_elems<I, Er>(&mut self, elems: I) -> Result<&mut Self, Er>
+    where
+        I: IntoIterator,
+        F: FnMut(&I::Item) -> Result<(), Er>,
+        T: Weldable<I::Item>,
+    {
+        for elem in elems {
+            self.try_elem(elem)?;
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use Welder;
+
+    #[test]
+    fn rejection_keeps_what_was_validated() {
+        let welder: Welder<_, Vec<_>> = Welder::new(0);
+        let mut welder = welder.validate_each(|elem: &i32| {
+            if *elem > 0 { Ok(()) } else { Err(*elem) }
+        });
+
+        assert_eq!(Some(-3), welder.try_elems(vec![1, 2, -3, 4]).err());
+        welder.try_elem(5).unwrap();
+
+        let welder = welder.into_inner();
+        assert_eq!(3, welder.len());
+        assert_eq!(vec![0, 1, 0, 2, 0, 5], welder.weld());
+    }
+}
@@ -0,0 +1,304 @@
+use std::borrow::Cow;
+use std::collections::VecDeque;
+
+use Welder;
+
+/// A target a `Welder` can accumulate elements of type `E` into.
+///
+/// Each target pushes its elements the fastest way it knows,
+/// `String`s push the `str`s and `Vec`s extend themselves from the slices.
+///
+/// # Examples
+///
+/// ```
+/// use welder::{Weldable, Welder};
+///
+/// #[derive(Default)]
+/// struct Count(usize);
+///
+/// impl<'a> Weldable<&'a str> for Count {
+///     fn weld_elem(&mut self, elem: &'a str) {
+///         self.0 += elem.len();
+///     }
+/// }
+///
+/// let welder: Welder<_, Count> = Welder::with_start("", "foo").elem_no_glue("bar");
+///
+/// assert_eq!(6, welder.weld().0);
+/// ```
+pub trait Weldable<E> {
+    /// Push an element at the end of the target.
+    fn weld_elem(&mut self, elem: E);
+
+    /// The number of values welding the element would add to the target,
+    /// if it is known without welding it.
+    fn len_hint(&self, _elem: &E) -> Option<usize> {
+        None
+    }
+
+    /// Push each element at the end of the target.
+    fn weld_elems<I>(&mut self, elems: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        for elem in elems {
+            self.weld_elem(elem)
+        }
+    }
+}
+
+impl<'a> Weldable<&'a str> for String {
+    fn weld_elem(&mut self, elem: &'a str) {
+        self.push_str(elem)
+    }
+
+    fn len_hint(&self, elem: &&'a str) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl<'a, 'b> Weldable<&'a &'b str> for String {
+    fn weld_elem(&mut self, elem: &'a &'b str) {
+        self.push_str(elem)
+    }
+
+    fn len_hint(&self, elem: &&'a &'b str) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl<'a> Weldable<&'a String> for String {
+    fn weld_elem(&mut self, elem: &'a String) {
+        self.push_str(elem)
+    }
+
+    fn len_hint(&self, elem: &&'a String) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl Weldable<String> for String {
+    fn weld_elem(&mut self, elem: String) {
+        self.push_str(&elem)
+    }
+
+    fn len_hint(&self, elem: &String) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl Weldable<Box<str>> for String {
+    fn weld_elem(&mut self, elem: Box<str>) {
+        self.push_str(&elem)
+    }
+
+    fn len_hint(&self, elem: &Box<str>) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl<'a> Weldable<Cow<'a, str>> for String {
+    fn weld_elem(&mut self, elem: Cow<'a, str>) {
+        self.push_str(&elem)
+    }
+
+    fn len_hint(&self, elem: &Cow<'a, str>) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl Weldable<char> for String {
+    fn weld_elem(&mut self, elem: char) {
+        self.push(elem)
+    }
+
+    fn len_hint(&self, elem: &char) -> Option<usize> {
+        Some(elem.len_utf8())
+    }
+
+    fn weld_elems<I>(&mut self, elems: I)
+    where
+        I: IntoIterator<Item = char>,
+    {
+        self.extend(elems)
+    }
+}
+
+impl<'a> Weldable<&'a char> for String {
+    fn weld_elem(&mut self, elem: &'a char) {
+        self.push(*elem)
+    }
+
+    fn len_hint(&self, elem: &&'a char) -> Option<usize> {
+        Some(elem.len_utf8())
+    }
+}
+
+impl<G> Weldable<Welder<G, String>> for String {
+    fn weld_elem(&mut self, elem: Welder<G, String>) {
+        self.push_str(&elem.weld())
+    }
+
+    fn len_hint(&self, elem: &Welder<G, String>) -> Option<usize> {
+        Some(elem.as_str().len())
+    }
+}
+
+impl<E> Weldable<E> for Vec<E> {
+    fn weld_elem(&mut self, elem: E) {
+        self.push(elem)
+    }
+
+    fn len_hint(&self, _elem: &E) -> Option<usize> {
+        Some(1)
+    }
+
+    fn weld_elems<I>(&mut self, elems: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        self.extend(elems)
+    }
+}
+
+impl<G, E> Weldable<Welder<G, Vec<E>>> for Vec<E> {
+    fn weld_elem(&mut self, elem: Welder<G, Vec<E>>) {
+        self.append(&mut elem.weld())
+    }
+
+    fn len_hint(&self, elem: &Welder<G, Vec<E>>) -> Option<usize> {
+        Some(elem.as_slice().len())
+    }
+}
+
+impl<E> Weldable<Vec<E>> for Vec<E> {
+    fn weld_elem(&mut self, mut elem: Vec<E>) {
+        self.append(&mut elem)
+    }
+
+    fn len_hint(&self, elem: &Vec<E>) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl<'a, E: Copy> Weldable<&'a E> for Vec<E> {
+    fn weld_elem(&mut self, elem: &'a E) {
+        self.push(*elem)
+    }
+
+    fn len_hint(&self, _elem: &&'a E) -> Option<usize> {
+        Some(1)
+    }
+}
+
+impl<'a, E: Clone> Weldable<&'a [E]> for Vec<E> {
+    fn weld_elem(&mut self, elem: &'a [E]) {
+        self.extend_from_slice(elem)
+    }
+
+    fn len_hint(&self, elem: &&'a [E]) -> Option<usize> {
+        Some(elem.len())
+    }
+}
+
+impl<E> Weldable<E> for VecDeque<E> {
+    fn weld_elem(&mut self, elem: E) {
+        self.push_back(elem)
+    }
+
+    fn len_hint(&self, _elem: &E) -> Option<usize> {
+        Some(1)
+    }
+
+    fn weld_elems<I>(&mut self, elems: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        self.extend(elems)
+    }
+}
+
+impl<E, T: Weldable<E>> Weldable<E> for &mut T {
+    fn weld_elem(&mut self, elem: E) {
+        (**self).weld_elem(elem)
+    }
+
+    fn len_hint(&self, elem: &E) -> Option<usize> {
+        (**self).len_hint(elem)
+    }
+
+    fn weld_elems<I>(&mut self, elems: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        (**self).weld_elems(elems)
+    }
+}
+
+/// A target wrapping any collection that can be extended with the elements,
+/// for the collections that do not implement `Weldable` themselves.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+/// use welder::{Extended, Welder};
+///
+/// let welder = Welder::with_start("none", "serde").elems(vec!["std", "serde"]);
+///
+/// let Extended(features): Extended<BTreeSet<_>> = welder.weld();
+/// assert_eq!(vec!["none", "serde", "std"], features.into_iter().collect::<Vec<_>>());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Extended<T>(pub T);
+
+impl<T> Extended<T> {
+    /// Retrieve the wrapped collection.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<E, T: Extend<E>> Weldable<E> for Extended<T> {
+    fn weld_elem(&mut self, elem: E) {
+        self.0.extend(Some(elem))
+    }
+
+    fn weld_elems<I>(&mut self, elems: I)
+    where
+        I: IntoIterator<Item = E>,
+    {
+        self.0.extend(elems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{HashSet, VecDeque};
+    use super::Extended;
+    use Welder;
+
+    #[test]
+    fn weldable_slices_into_vec() {
+        let vec: Vec<u8> = Welder::with_start(&[0u8][..], &b"foo"[..]).elem(&b"bar"[..]).weld();
+
+        assert_eq!(b"foo\0bar", vec.as_slice());
+    }
+
+    #[test]
+    fn weldable_vec_deque() {
+        let deque: VecDeque<_> = Welder::with_start(0, 1).elem(2).weld();
+
+        assert_eq!(vec![1, 0, 2], Vec::from(deque));
+    }
+
+    #[test]
+    fn weldable_extended_hash_set() {
+        let welder = Welder::with_start(0, 1).elems(vec![2, 1]);
+        let set: Extended<HashSet<_>> = welder.weld();
+
+        let mut values: Vec<_> = set.into_inner().into_iter().collect();
+        values.sort();
+        assert_eq!(vec![0, 1, 2], values);
+    }
+}
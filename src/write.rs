@@ -0,0 +1,66 @@
+use std::io;
+
+use {Glue, Welder};
+
+/// The bytes written until a call to `flush` form one element,
+/// a glue is put in front of each element if anything has been added before it.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Write;
+/// use welder::Welder;
+///
+/// let mut welder: Welder<_, Vec<u8>> = Welder::new(b'\n');
+///
+/// write!(welder, "{}", "first").unwrap();
+/// welder.write_all(b" element").unwrap();
+/// welder.flush().unwrap();
+/// welder.write_all(b"second").unwrap();
+///
+/// assert_eq!(b"first element\nsecond", welder.weld().as_slice());
+/// ```
+impl<G: Glue<Vec<u8>>> io::Write for Welder<G, Vec<u8>> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.is_empty() {
+            return Ok(0)
+        }
+
+        if !self.chunk_open {
+            if self.elems != 0 {
+                self.glue.apply(&mut self.welded);
+                self.glues += 1;
+            }
+            self.count_elems(1);
+            self.chunk_open = true;
+        }
+        self.welded.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.chunk_open = false;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+    use Welder;
+
+    #[test]
+    fn io_write_chunks_are_elements() {
+        let mut welder: Welder<_, Vec<u8>> = Welder::with_start(b',', b'a');
+        welder.write_all(b"b").unwrap();
+        welder.write_all(b"c").unwrap();
+        welder.flush().unwrap();
+        welder.flush().unwrap();
+        welder.write_all(b"d").unwrap();
+        welder.push_elem(b'e');
+        welder.write_all(b"f").unwrap();
+
+        assert_eq!(5, welder.len());
+        assert_eq!(b"a,bc,d,e,f", welder.weld().as_slice());
+    }
+}
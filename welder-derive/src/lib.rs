@@ -0,0 +1,121 @@
+//! The `Weld` derive, it is re-exported by `welder` behind the `derive` feature.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+#[macro_use]
+extern crate quote;
+extern crate syn;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{Data, DeriveInput, Index, LitStr};
+
+/// Generate a `fn weld(&self) -> String` that welds the fields of the struct, in order.
+///
+/// The glue is given by `#[weld(glue = "...")]` on the struct and defaults to `", "`.
+/// Each field is formatted with `{}`, or with `#[weld(format = "...")]`,
+/// and `#[weld(skip)]` leaves it out.
+///
+/// # Examples
+///
+/// ```
+/// extern crate welder;
+///
+/// use welder::Weld;
+///
+/// #[derive(Weld)]
+/// #[weld(glue = ",")]
+/// struct Row {
+///     name: &'static str,
+///     #[weld(format = "{:.2}")]
+///     price: f64,
+///     #[weld(skip)]
+///     #[allow(dead_code)]
+///     internal_id: u64,
+///     stock: u32,
+/// }
+///
+/// # fn main() {
+/// let row = Row { name: "apple", price: 0.5, internal_id: 42, stock: 12 };
+///
+/// assert_eq!("apple,0.50,12", &row.weld());
+/// # }
+/// ```
+#[proc_macro_derive(Weld, attributes(weld))]
+pub fn derive_weld(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    match expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut glue = LitStr::new(", ", proc_macro2::Span::call_site());
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("weld")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("glue") {
+                glue = meta.value()?.parse()?;
+                Ok(())
+            } else {
+                Err(meta.error("expected `glue`"))
+            }
+        })?;
+    }
+
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(syn::Error::new_spanned(&input.ident, "Weld can only be derived for structs")),
+    };
+
+    let mut elems = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        let mut format = LitStr::new("{}", proc_macro2::Span::call_site());
+        let mut skip = false;
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("weld")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("format") {
+                    format = meta.value()?.parse()?;
+                    Ok(())
+                } else if meta.path.is_ident("skip") {
+                    skip = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `format` or `skip`"))
+                }
+            })?;
+        }
+
+        if !skip {
+            let member = match field.ident {
+                Some(ref ident) => quote!(#ident),
+                None => {
+                    let index = Index::from(i);
+                    quote!(#index)
+                },
+            };
+            elems.push(quote!(format_args!(#format, self.#member)));
+        }
+    }
+
+    let body = match elems.split_first() {
+        Some((first, rest)) => quote! {
+            let welder: ::welder::Welder<&str, String> = ::welder::Welder::new(#glue);
+            let welder = welder.elem_fmt_no_glue(#first);
+            #( let welder = welder.elem_fmt(#rest); )*
+            welder.weld()
+        },
+        None => quote!(String::new()),
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Weld the fields of this struct, in order.
+            pub fn weld(&self) -> String {
+                #body
+            }
+        }
+    })
+}